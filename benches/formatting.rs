@@ -0,0 +1,34 @@
+//! Conversion of short and long sequences to strings via [Display](std::fmt::Display):
+//!
+//! ```bash
+//! cargo bench --bench formatting
+//! ```
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use digit_sequence::*;
+use std::hint::black_box;
+
+const CARD_NUMBER: &str = "4111111111111111";
+const DIGITS: usize = 1_000_000;
+
+fn display(c: &mut Criterion) {
+    let card_number: DigitSequence = CARD_NUMBER.parse().unwrap();
+    let long_sequence: DigitSequence = "3141592653".repeat(DIGITS / 10).parse().unwrap();
+
+    let mut group = c.benchmark_group("display");
+
+    group.bench_function("card number to string", |b| {
+        b.iter(|| black_box(&card_number).to_string())
+    });
+
+    group.throughput(Throughput::Bytes(DIGITS as u64));
+
+    group.bench_function("1M digits to string", |b| {
+        b.iter(|| black_box(&long_sequence).to_string())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, display);
+criterion_main!(benches);
@@ -0,0 +1,33 @@
+//! Parsing of a large ASCII digit string - such as the content
+//! of a digit file - via [FromStr](std::str::FromStr) and
+//! [DigitSequence::from_ascii]:
+//!
+//! ```bash
+//! cargo bench --bench parsing
+//! ```
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use digit_sequence::*;
+use std::hint::black_box;
+
+const DIGITS: usize = 1_000_000;
+
+fn parsing(c: &mut Criterion) {
+    let source = "3141592653".repeat(DIGITS / 10);
+
+    let mut group = c.benchmark_group("parsing");
+    group.throughput(Throughput::Bytes(DIGITS as u64));
+
+    group.bench_function("1M digits from str", |b| {
+        b.iter(|| black_box(source.as_str()).parse::<DigitSequence>().unwrap())
+    });
+
+    group.bench_function("1M digits from ASCII bytes", |b| {
+        b.iter(|| DigitSequence::from_ascii(black_box(source.as_bytes())).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, parsing);
+criterion_main!(benches);
@@ -0,0 +1,66 @@
+//! Creation and cloning of typical short sequences - phone numbers,
+//! card numbers and OTPs - plus a long one, to be compared
+//! with and without the `smallvec` feature:
+//!
+//! ```bash
+//! cargo bench --bench storage
+//! cargo bench --bench storage --features smallvec
+//! ```
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use digit_sequence::*;
+use std::hint::black_box;
+
+const OTP: u32 = 287082;
+const PHONE_NUMBER: u64 = 390_212_345_678;
+const CARD_NUMBER: &str = "4111111111111111";
+const LONG_NUMBER: &str = "3141592653589793238462643383279502884197";
+
+fn creation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("creation");
+
+    group.bench_function("OTP from u32", |b| {
+        b.iter(|| DigitSequence::from(black_box(OTP)))
+    });
+
+    group.bench_function("phone number from u64", |b| {
+        b.iter(|| DigitSequence::from(black_box(PHONE_NUMBER)))
+    });
+
+    group.bench_function("card number from str", |b| {
+        b.iter(|| black_box(CARD_NUMBER).parse::<DigitSequence>().unwrap())
+    });
+
+    group.bench_function("long number from str", |b| {
+        b.iter(|| black_box(LONG_NUMBER).parse::<DigitSequence>().unwrap())
+    });
+
+    group.finish();
+}
+
+fn cloning(c: &mut Criterion) {
+    let card_number: DigitSequence = CARD_NUMBER.parse().unwrap();
+
+    c.bench_function("cloning/card number", |b| {
+        b.iter(|| black_box(&card_number).clone())
+    });
+}
+
+fn batch(c: &mut Criterion) {
+    let card_numbers: Vec<String> = (0..1000u64)
+        .map(|index| format!("4{:015}", index * 7919))
+        .collect();
+
+    c.bench_function("batch/parsing and validating 1000 card numbers", |b| {
+        b.iter(|| {
+            card_numbers
+                .iter()
+                .map(|card_number| card_number.parse::<DigitSequence>().unwrap())
+                .filter(|sequence| sequence.luhn_is_valid())
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, creation, cloning, batch);
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+//! Compile-time macros for [digit-sequence](https://crates.io/crates/digit-sequence) -
+//! re-exported by that crate when its `macros` feature is enabled.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, LitStr};
+
+/// Parses a string literal of 0-9 digits at compile time, expanding to
+/// a `DigitSequence` - for example, to embed constants such as country prefixes.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let prefix = dseq!("0039");
+/// assert_eq!(prefix, [0, 0, 3, 9]);
+///
+/// assert_eq!(dseq!(""), DigitSequence::new());
+/// ```
+///
+/// Any char that is not a base-10 digit fails the build:
+///
+/// ```compile_fail
+/// use digit_sequence::*;
+///
+/// let prefix = dseq!("+39");
+/// ```
+#[proc_macro]
+pub fn dseq(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+    let text = literal.value();
+
+    if let Some(non_digit) = text.chars().find(|c| !c.is_ascii_digit()) {
+        return syn::Error::new(literal.span(), format!("Non-digit char: {}", non_digit))
+            .to_compile_error()
+            .into();
+    }
+
+    let digits = text.bytes().map(|byte| byte - b'0');
+
+    quote! {
+        ::digit_sequence::digits![#(#digits),*]
+    }
+    .into()
+}
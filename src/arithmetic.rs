@@ -0,0 +1,685 @@
+use crate::{CrateError, CrateResult, DigitSequence, DigitSlice, Digits};
+use alloc::vec;
+use core::cmp::Ordering;
+
+impl DigitSequence {
+    /// Sum of all the digits in the sequence - 0 for the empty sequence.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(DigitSequence::new().digit_sum(), 0);
+    ///
+    /// let sequence: DigitSequence = [9, 0, 8, 1].try_into()?;
+    /// assert_eq!(sequence.digit_sum(), 18);
+    ///
+    /// let huge: DigitSequence = "9".repeat(1000).parse()?;
+    /// assert_eq!(huge.digit_sum(), 9000);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn digit_sum(&self) -> u64 {
+        self.as_digit_slice().digit_sum()
+    }
+
+    /// Remainder of the division of the represented value by the given divisor.
+    ///
+    /// The remainder is computed digit by digit, so it works
+    /// on sequences of any length - with no risk of overflow;
+    /// the empty sequence is considered equivalent to 0.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 1987u16.into();
+    /// assert_eq!(sequence.remainder(10), 7);
+    /// assert_eq!(sequence.remainder(1987), 0);
+    /// assert_eq!(sequence.remainder(u64::MAX), 1987);
+    ///
+    /// let huge: DigitSequence = format!("1{}", "0".repeat(100)).parse()?;
+    /// assert_eq!(huge.remainder(7), 4);
+    ///
+    /// assert_eq!(DigitSequence::new().remainder(3), 0);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Just like the `%` operator on integers, this method panics if `divisor` is 0.
+    pub fn remainder(&self, divisor: u64) -> u64 {
+        self.as_digit_slice().remainder(divisor)
+    }
+
+    /// Arbitrary-precision subtraction; the result never has leading zeros,
+    /// and the empty sequence is considered equivalent to 0.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let minuend: DigitSequence = 1000u16.into();
+    /// let subtrahend: DigitSequence = [0, 0, 1].try_into()?;
+    /// assert_eq!(minuend.checked_sub(&subtrahend)?, [9, 9, 9]);
+    ///
+    /// assert_eq!(minuend.checked_sub(&minuend)?, [0]);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// A negative result is reported as [CrateError::Underflow]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let minuend: DigitSequence = 7u8.into();
+    /// let subtrahend: DigitSequence = 8u8.into();
+    ///
+    /// assert_eq!(minuend.checked_sub(&subtrahend), Err(CrateError::Underflow));
+    /// ```
+    pub fn checked_sub(&self, subtrahend: &DigitSequence) -> CrateResult<DigitSequence> {
+        if compare(&self.0, &subtrahend.0) == Ordering::Less {
+            return Err(CrateError::Underflow);
+        }
+
+        let mut result = Digits::from(significant_digits(&self.0));
+        sub_in_place(&mut result, significant_digits(&subtrahend.0));
+
+        Ok(DigitSequence(normalize(result)))
+    }
+
+    /// Arbitrary-precision integer division, returning both the quotient
+    /// and the remainder - without leading zeros.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let dividend: DigitSequence = 1000u16.into();
+    /// let divisor: DigitSequence = 7u8.into();
+    ///
+    /// let (quotient, remainder) = dividend.checked_div_rem(&divisor)?;
+    /// assert_eq!(quotient, [1, 4, 2]);
+    /// assert_eq!(remainder, [6]);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Unlike the `/` and `%` operators, which panic, dividing
+    /// by zero results in a [CrateError::DivisionByZero]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let dividend: DigitSequence = 1000u16.into();
+    /// let divisor: DigitSequence = [0, 0].try_into()?;
+    ///
+    /// assert_eq!(dividend.checked_div_rem(&divisor), Err(CrateError::DivisionByZero));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_div_rem(
+        &self,
+        divisor: &DigitSequence,
+    ) -> CrateResult<(DigitSequence, DigitSequence)> {
+        if significant_digits(&divisor.0).is_empty() {
+            return Err(CrateError::DivisionByZero);
+        }
+
+        let (quotient, remainder) = div_rem(&self.0, &divisor.0);
+
+        Ok((DigitSequence(quotient), DigitSequence(remainder)))
+    }
+}
+
+impl DigitSlice<'_> {
+    /// Just like [DigitSequence::digit_sum].
+    pub fn digit_sum(&self) -> u64 {
+        self.0.iter().map(|&digit| digit as u64).sum()
+    }
+
+    /// Just like [DigitSequence::remainder].
+    pub fn remainder(&self, divisor: u64) -> u64 {
+        assert!(divisor != 0, "Division by zero");
+
+        let divisor = divisor as u128;
+
+        self.0.iter().fold(0u128, |remainder, &digit| {
+            (remainder * 10 + digit as u128) % divisor
+        }) as u64
+    }
+}
+
+/// Returns the digits without the leading zeros - hence, the empty
+/// slice for any representation of 0.
+pub(crate) fn significant_digits(digits: &[u8]) -> &[u8] {
+    let first_non_zero = digits
+        .iter()
+        .position(|&digit| digit != 0)
+        .unwrap_or(digits.len());
+
+    &digits[first_non_zero..]
+}
+
+/// Canonical form of an arithmetic result: no leading zeros,
+/// with 0 represented by a single digit.
+pub(crate) fn normalize(digits: Digits) -> Digits {
+    let leading_zeros = digits.len() - significant_digits(&digits).len();
+
+    if leading_zeros == digits.len() {
+        Digits::from(&[0][..])
+    } else if leading_zeros == 0 {
+        digits
+    } else {
+        Digits::from(&digits[leading_zeros..])
+    }
+}
+
+pub(crate) fn add(left: &[u8], right: &[u8]) -> Digits {
+    let mut result = Digits::with_capacity(left.len().max(right.len()) + 1);
+    let mut left_digits = left.iter().rev();
+    let mut right_digits = right.iter().rev();
+    let mut carry = 0;
+
+    loop {
+        let (left_digit, right_digit) = match (left_digits.next(), right_digits.next()) {
+            (None, None) => break,
+            (left_digit, right_digit) => (
+                left_digit.copied().unwrap_or(0),
+                right_digit.copied().unwrap_or(0),
+            ),
+        };
+
+        let total = left_digit + right_digit + carry;
+        result.push(total % 10);
+        carry = total / 10;
+    }
+
+    if carry > 0 {
+        result.push(carry);
+    }
+
+    result.reverse();
+    normalize(result)
+}
+
+pub(crate) fn mul_small(digits: &[u8], factor: u64) -> Digits {
+    let factor = factor as u128;
+    let mut result = Digits::with_capacity(digits.len() + 20);
+    let mut carry: u128 = 0;
+
+    for &digit in digits.iter().rev() {
+        let product = digit as u128 * factor + carry;
+        result.push((product % 10) as u8);
+        carry = product / 10;
+    }
+
+    while carry > 0 {
+        result.push((carry % 10) as u8);
+        carry /= 10;
+    }
+
+    result.reverse();
+    normalize(result)
+}
+
+pub(crate) fn mul(left: &[u8], right: &[u8]) -> Digits {
+    let mut reversed_result = vec![0u64; left.len() + right.len()];
+
+    for (left_index, &left_digit) in left.iter().rev().enumerate() {
+        for (right_index, &right_digit) in right.iter().rev().enumerate() {
+            reversed_result[left_index + right_index] += left_digit as u64 * right_digit as u64;
+        }
+    }
+
+    let mut carry = 0;
+
+    for term in reversed_result.iter_mut() {
+        let total = *term + carry;
+        *term = total % 10;
+        carry = total / 10;
+    }
+
+    normalize(
+        reversed_result
+            .into_iter()
+            .rev()
+            .map(|digit| digit as u8)
+            .collect(),
+    )
+}
+
+/// Numeric comparison, ignoring leading zeros.
+pub(crate) fn compare(left: &[u8], right: &[u8]) -> Ordering {
+    let left = significant_digits(left);
+    let right = significant_digits(right);
+
+    left.len().cmp(&right.len()).then_with(|| left.cmp(right))
+}
+
+/// Subtracts `subtrahend` from `minuend`, which must not be smaller;
+/// the result never has leading zeros - so 0 is the empty vector.
+pub(crate) fn sub_in_place(minuend: &mut Digits, subtrahend: &[u8]) {
+    let mut borrow = 0;
+    let mut subtrahend_digits = subtrahend.iter().rev();
+
+    for minuend_digit in minuend.iter_mut().rev() {
+        let subtracted = subtrahend_digits.next().copied().unwrap_or(0) + borrow;
+
+        if *minuend_digit >= subtracted {
+            *minuend_digit -= subtracted;
+            borrow = 0;
+        } else {
+            *minuend_digit = *minuend_digit + 10 - subtracted;
+            borrow = 1;
+        }
+    }
+
+    debug_assert_eq!(
+        borrow, 0,
+        "The minuend must not be smaller than the subtrahend"
+    );
+
+    let leading_zeros = minuend.len() - significant_digits(minuend).len();
+    minuend.drain(..leading_zeros);
+}
+
+/// Long division, returning both the quotient and the remainder.
+///
+/// # Panics
+///
+/// If the divisor is 0.
+pub(crate) fn div_rem(dividend: &[u8], divisor: &[u8]) -> (Digits, Digits) {
+    let divisor = significant_digits(divisor);
+    assert!(!divisor.is_empty(), "Division by zero");
+
+    let mut quotient = Digits::with_capacity(dividend.len());
+    let mut remainder = Digits::with_capacity(divisor.len() + 1);
+
+    for &digit in dividend {
+        if !remainder.is_empty() || digit != 0 {
+            remainder.push(digit);
+        }
+
+        let mut quotient_digit = 0;
+
+        while compare(&remainder, divisor) != Ordering::Less {
+            sub_in_place(&mut remainder, divisor);
+            quotient_digit += 1;
+        }
+
+        quotient.push(quotient_digit);
+    }
+
+    (normalize(quotient), normalize(remainder))
+}
+
+/// Arithmetic addition between references to [DigitSequence]
+/// is infallible and supports arbitrary precision; the result
+/// never has leading zeros, and the empty sequence is
+/// considered equivalent to 0.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let left: DigitSequence = 999u16.into();
+/// let right: DigitSequence = [0, 0, 1].try_into()?;
+/// assert_eq!(&left + &right, [1, 0, 0, 0]);
+///
+/// let huge: DigitSequence = "9".repeat(50).parse()?;
+/// let one: DigitSequence = 1u8.into();
+/// assert_eq!((&huge + &one).to_string(), format!("1{}", "0".repeat(50)));
+///
+/// assert_eq!(&DigitSequence::new() + &DigitSequence::new(), [0]);
+///
+/// # Ok(())
+/// # }
+/// ```
+impl core::ops::Add<&DigitSequence> for &DigitSequence {
+    type Output = DigitSequence;
+
+    fn add(self, rhs: &DigitSequence) -> DigitSequence {
+        DigitSequence(add(&self.0, &rhs.0))
+    }
+}
+
+/// Arithmetic addition is also available on owned [DigitSequence] values.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let left: DigitSequence = 90u8.into();
+/// let right: DigitSequence = 12u8.into();
+///
+/// assert_eq!(left + right, [1, 0, 2]);
+/// ```
+impl core::ops::Add for DigitSequence {
+    type Output = DigitSequence;
+
+    fn add(self, rhs: DigitSequence) -> DigitSequence {
+        &self + &rhs
+    }
+}
+
+/// Arithmetic multiplication between references to [DigitSequence]
+/// is infallible and supports arbitrary precision; the result
+/// never has leading zeros, and the empty sequence is
+/// considered equivalent to 0.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let left: DigitSequence = 25u8.into();
+/// let right: DigitSequence = [0, 4].try_into()?;
+/// assert_eq!(&left * &right, [1, 0, 0]);
+///
+/// let huge: DigitSequence = "9".repeat(30).parse()?;
+/// assert_eq!(
+///     (&huge * &huge).to_string(),
+///     format!("{}8{}1", "9".repeat(29), "0".repeat(29))
+/// );
+///
+/// assert_eq!(&huge * &DigitSequence::new(), [0]);
+///
+/// # Ok(())
+/// # }
+/// ```
+impl core::ops::Mul<&DigitSequence> for &DigitSequence {
+    type Output = DigitSequence;
+
+    fn mul(self, rhs: &DigitSequence) -> DigitSequence {
+        DigitSequence(mul(&self.0, &rhs.0))
+    }
+}
+
+/// Arithmetic multiplication is also available on owned [DigitSequence] values.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let left: DigitSequence = 90u8.into();
+/// let right: DigitSequence = 12u8.into();
+///
+/// assert_eq!(left * right, [1, 0, 8, 0]);
+/// ```
+impl core::ops::Mul for DigitSequence {
+    type Output = DigitSequence;
+
+    fn mul(self, rhs: DigitSequence) -> DigitSequence {
+        &self * &rhs
+    }
+}
+
+/// Summing [DigitSequence] values - for example, amounts stored as digit
+/// strings - supports arbitrary precision; just like addition, the result
+/// never has leading zeros, and the sum of no values is 0.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let amounts: Vec<DigitSequence> = vec!["1999".parse()?, "0250".parse()?, "17".parse()?];
+///
+/// let total: DigitSequence = amounts.iter().sum();
+/// assert_eq!(total, [2, 2, 6, 6]);
+///
+/// let huge: DigitSequence = (0..10).map(|_| "9".repeat(40).parse::<DigitSequence>().unwrap()).sum();
+/// assert_eq!(huge.to_string(), format!("9{}0", "9".repeat(39)));
+///
+/// assert_eq!(std::iter::empty::<DigitSequence>().sum::<DigitSequence>(), [0]);
+///
+/// # Ok(())
+/// # }
+/// ```
+impl core::iter::Sum for DigitSequence {
+    fn sum<I: Iterator<Item = DigitSequence>>(iter: I) -> DigitSequence {
+        iter.fold(DigitSequence(normalize(Digits::new())), |total, value| {
+            &total + &value
+        })
+    }
+}
+
+/// Summing references to [DigitSequence] values is also supported.
+impl<'a> core::iter::Sum<&'a DigitSequence> for DigitSequence {
+    fn sum<I: Iterator<Item = &'a DigitSequence>>(iter: I) -> DigitSequence {
+        iter.fold(DigitSequence(normalize(Digits::new())), |total, value| {
+            &total + value
+        })
+    }
+}
+
+/// Multiplying [DigitSequence] values supports arbitrary precision;
+/// just like multiplication, the result never has leading zeros,
+/// and the product of no values is 1.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let factorial: DigitSequence = (1..=25u8).map(DigitSequence::from).product();
+/// assert_eq!(factorial.to_string(), "15511210043330985984000000");
+///
+/// let factors: Vec<DigitSequence> = vec![12u8.into(), 5u8.into()];
+/// assert_eq!(factors.iter().product::<DigitSequence>(), [6, 0]);
+///
+/// assert_eq!(std::iter::empty::<DigitSequence>().product::<DigitSequence>(), [1]);
+/// ```
+impl core::iter::Product for DigitSequence {
+    fn product<I: Iterator<Item = DigitSequence>>(iter: I) -> DigitSequence {
+        iter.fold(DigitSequence::from(1u8), |total, value| &total * &value)
+    }
+}
+
+/// Multiplying references to [DigitSequence] values is also supported.
+impl<'a> core::iter::Product<&'a DigitSequence> for DigitSequence {
+    fn product<I: Iterator<Item = &'a DigitSequence>>(iter: I) -> DigitSequence {
+        iter.fold(DigitSequence::from(1u8), |total, value| &total * value)
+    }
+}
+
+/// Arithmetic division between references to [DigitSequence] is
+/// the integer division, supporting arbitrary precision; the result
+/// never has leading zeros, and the empty sequence is
+/// considered equivalent to 0.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let dividend: DigitSequence = 1000u16.into();
+/// let divisor: DigitSequence = [0, 7].try_into()?;
+/// assert_eq!(&dividend / &divisor, [1, 4, 2]);
+///
+/// let huge: DigitSequence = format!("1{}", "0".repeat(50)).parse()?;
+/// let divisor: DigitSequence = format!("1{}", "0".repeat(48)).parse()?;
+/// assert_eq!(&huge / &divisor, [1, 0, 0]);
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Just like the `/` operator on integers, if the divisor is 0.
+impl core::ops::Div<&DigitSequence> for &DigitSequence {
+    type Output = DigitSequence;
+
+    fn div(self, rhs: &DigitSequence) -> DigitSequence {
+        DigitSequence(div_rem(&self.0, &rhs.0).0)
+    }
+}
+
+/// Arithmetic division is also available on owned [DigitSequence] values.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let dividend: DigitSequence = 90u8.into();
+/// let divisor: DigitSequence = 12u8.into();
+///
+/// assert_eq!(dividend / divisor, [7]);
+/// ```
+impl core::ops::Div for DigitSequence {
+    type Output = DigitSequence;
+
+    fn div(self, rhs: DigitSequence) -> DigitSequence {
+        &self / &rhs
+    }
+}
+
+/// The remainder of the integer division between references to [DigitSequence]
+/// supports arbitrary precision; the result never has leading zeros,
+/// and the empty sequence is considered equivalent to 0.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let dividend: DigitSequence = 1000u16.into();
+/// let divisor: DigitSequence = [0, 7].try_into()?;
+/// assert_eq!(&dividend % &divisor, [6]);
+///
+/// let huge: DigitSequence = format!("1{}", "0".repeat(50)).parse()?;
+/// let divisor: DigitSequence = "9".repeat(25).parse()?;
+/// assert_eq!(&huge % &divisor, [1]);
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Just like the `%` operator on integers, if the divisor is 0.
+impl core::ops::Rem<&DigitSequence> for &DigitSequence {
+    type Output = DigitSequence;
+
+    fn rem(self, rhs: &DigitSequence) -> DigitSequence {
+        DigitSequence(div_rem(&self.0, &rhs.0).1)
+    }
+}
+
+/// The remainder operator is also available on owned [DigitSequence] values.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let dividend: DigitSequence = 90u8.into();
+/// let divisor: DigitSequence = 12u8.into();
+///
+/// assert_eq!(dividend % divisor, [6]);
+/// ```
+impl core::ops::Rem for DigitSequence {
+    type Output = DigitSequence;
+
+    fn rem(self, rhs: DigitSequence) -> DigitSequence {
+        &self % &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    fn normalized(digits: &[u8]) -> Vec<u8> {
+        normalize(digits.into()).to_vec()
+    }
+
+    speculate! {
+        describe "Normalizing digits" {
+            it "should keep a normalized vector" {
+                eq!(normalized(&[9, 0]), vec![9, 0]);
+            }
+
+            it "should remove leading zeros" {
+                eq!(normalized(&[0, 0, 9, 0]), vec![9, 0]);
+            }
+
+            it "should turn zeros into a single 0" {
+                eq!(normalized(&[0, 0, 0]), vec![0]);
+            }
+
+            it "should turn the empty vector into a single 0" {
+                eq!(normalized(&[]), vec![0]);
+            }
+        }
+
+        describe "Adding digits" {
+            it "should match integer addition" {
+                for (left, right) in [(0u64, 0u64), (1, 9), (95, 7), (123456, 987654321)] {
+                    let expected = DigitSequence::from(left + right);
+
+                    eq!(add(&DigitSequence::from(left).0, &DigitSequence::from(right).0), expected.0);
+                }
+            }
+        }
+
+        describe "Multiplying digits" {
+            it "should match integer multiplication" {
+                for (left, right) in [(0u64, 9u64), (1, 0), (95, 7), (123456, u64::MAX), (u64::MAX, u64::MAX)] {
+                    let expected = DigitSequence::from(left as u128 * right as u128);
+
+                    eq!(mul(&DigitSequence::from(left).0, &DigitSequence::from(right).0), expected.0);
+                }
+            }
+        }
+
+        describe "Comparing digits" {
+            it "should ignore leading zeros" {
+                eq!(compare(&[0, 0, 9], &[9]), Ordering::Equal);
+                eq!(compare(&[0, 1, 0], &[9]), Ordering::Greater);
+                eq!(compare(&[], &[0, 0]), Ordering::Equal);
+            }
+        }
+
+        describe "Subtracting digits" {
+            it "should match integer subtraction" {
+                for (left, right) in [(0u64, 0u64), (10, 9), (1000, 1), (987654321, 123456), (u64::MAX, 1)] {
+                    let expected = DigitSequence::from(left - right);
+
+                    let mut actual = DigitSequence::from(left).0;
+                    sub_in_place(&mut actual, &DigitSequence::from(right).0);
+
+                    eq!(normalize(actual), expected.0);
+                }
+            }
+        }
+
+        describe "Dividing digits" {
+            it "should match integer division" {
+                for (left, right) in [(0u64, 7u64), (6, 7), (7, 7), (1000, 7), (u64::MAX, 97), (u64::MAX, u64::MAX / 3)] {
+                    let (quotient, remainder) = div_rem(&DigitSequence::from(left).0, &DigitSequence::from(right).0);
+
+                    eq!(quotient, DigitSequence::from(left / right).0);
+                    eq!(remainder, DigitSequence::from(left % right).0);
+                }
+            }
+
+            it "should panic on division by zero" {
+                let result = std::panic::catch_unwind(|| div_rem(&[1], &[0, 0]));
+
+                assert!(result.is_err());
+            }
+        }
+
+        describe "Multiplying digits by a small factor" {
+            it "should match integer multiplication" {
+                for (digits, factor) in [(0u64, 9u64), (1, 0), (95, 7), (123456, u64::MAX)] {
+                    let expected = DigitSequence::from(digits as u128 * factor as u128);
+
+                    eq!(mul_small(&DigitSequence::from(digits).0, factor), expected.0);
+                }
+            }
+        }
+    }
+}
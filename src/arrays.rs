@@ -78,7 +78,7 @@ impl<const N: usize> TryFrom<&[u8; N]> for DigitSequence {
 /// ```
 impl<const N: usize> PartialEq<[u8; N]> for DigitSequence {
     fn eq(&self, other: &[u8; N]) -> bool {
-        self.0 == *other
+        self.0[..] == other[..]
     }
 }
 
@@ -101,6 +101,6 @@ impl<const N: usize> PartialEq<[u8; N]> for DigitSequence {
 /// ```
 impl<const N: usize> PartialEq<&[u8; N]> for DigitSequence {
     fn eq(&self, other: &&[u8; N]) -> bool {
-        self.0 == **other
+        self.0[..] == other[..]
     }
 }
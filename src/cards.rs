@@ -0,0 +1,153 @@
+use crate::DigitSequence;
+
+/// Payment card brands, as detected by [DigitSequence::card_brand].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CardBrand {
+    /// Visa: prefix 4; 13, 16 or 19 digits.
+    Visa,
+
+    /// Mastercard: prefixes 51-55 and 2221-2720; 16 digits.
+    Mastercard,
+
+    /// American Express: prefixes 34 and 37; 15 digits.
+    Amex,
+
+    /// Discover: prefixes 6011, 622126-622925, 644-649 and 65; 16 to 19 digits.
+    Discover,
+
+    /// Diners Club: prefixes 300-305, 36, 38 and 39; 14 to 19 digits.
+    DinersClub,
+
+    /// JCB: prefixes 3528-3589; 16 to 19 digits.
+    Jcb,
+
+    /// UnionPay: prefix 62; 16 to 19 digits.
+    UnionPay,
+}
+
+fn prefix_value(digits: &[u8], len: usize) -> u32 {
+    digits
+        .get(..len)
+        .map(|prefix| {
+            prefix
+                .iter()
+                .fold(0, |value, &digit| value * 10 + digit as u32)
+        })
+        .unwrap_or(0)
+}
+
+impl DigitSequence {
+    /// Detects the brand of a card number (PAN) from its issuer identification
+    /// number (IIN) range and its length - returning [None] when no brand matches.
+    ///
+    /// The Luhn check digit is *not* verified: please, combine this method
+    /// with [luhn_is_valid](DigitSequence::luhn_is_valid) to fully validate a card number.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let visa: DigitSequence = "4111111111111111".parse()?;
+    /// assert_eq!(visa.card_brand(), Some(CardBrand::Visa));
+    ///
+    /// let mastercard: DigitSequence = "2221000000000009".parse()?;
+    /// assert_eq!(mastercard.card_brand(), Some(CardBrand::Mastercard));
+    ///
+    /// let amex: DigitSequence = "378282246310005".parse()?;
+    /// assert_eq!(amex.card_brand(), Some(CardBrand::Amex));
+    ///
+    /// let wrong_length: DigitSequence = "37828224631000".parse()?;
+    /// assert_eq!(wrong_length.card_brand(), None);
+    ///
+    /// let unknown: DigitSequence = "9111111111111111".parse()?;
+    /// assert_eq!(unknown.card_brand(), None);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn card_brand(&self) -> Option<CardBrand> {
+        let len = self.0.len();
+        let prefix = |prefix_len| prefix_value(&self.0, prefix_len);
+
+        let brand = if prefix(1) == 4 {
+            CardBrand::Visa
+        } else if (51..=55).contains(&prefix(2)) || (2221..=2720).contains(&prefix(4)) {
+            CardBrand::Mastercard
+        } else if [34, 37].contains(&prefix(2)) {
+            CardBrand::Amex
+        } else if prefix(4) == 6011
+            || (622126..=622925).contains(&prefix(6))
+            || (644..=649).contains(&prefix(3))
+            || prefix(2) == 65
+        {
+            CardBrand::Discover
+        } else if (300..=305).contains(&prefix(3)) || [36, 38, 39].contains(&prefix(2)) {
+            CardBrand::DinersClub
+        } else if (3528..=3589).contains(&prefix(4)) {
+            CardBrand::Jcb
+        } else if prefix(2) == 62 {
+            CardBrand::UnionPay
+        } else {
+            return None;
+        };
+
+        let valid_length = match brand {
+            CardBrand::Visa => [13, 16, 19].contains(&len),
+            CardBrand::Mastercard => len == 16,
+            CardBrand::Amex => len == 15,
+            CardBrand::DinersClub => (14..=19).contains(&len),
+            CardBrand::Discover | CardBrand::Jcb | CardBrand::UnionPay => (16..=19).contains(&len),
+        };
+
+        valid_length.then_some(brand)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Detecting the card brand" {
+            fn test_case(card_number: &str, expected_brand: Option<CardBrand>) {
+                let sequence: DigitSequence = card_number.parse().unwrap();
+
+                eq!(sequence.card_brand(), expected_brand);
+            }
+
+            it "should detect Visa" {
+                test_case("4222222222222", Some(CardBrand::Visa));
+            }
+
+            it "should detect Mastercard" {
+                test_case("5555555555554444", Some(CardBrand::Mastercard));
+            }
+
+            it "should detect Discover" {
+                test_case("6011111111111117", Some(CardBrand::Discover));
+            }
+
+            it "should detect co-branded Discover before UnionPay" {
+                test_case("6221260000000000", Some(CardBrand::Discover));
+            }
+
+            it "should detect Diners Club" {
+                test_case("30569309025904", Some(CardBrand::DinersClub));
+            }
+
+            it "should detect JCB" {
+                test_case("3530111333300000", Some(CardBrand::Jcb));
+            }
+
+            it "should detect UnionPay" {
+                test_case("6200000000000005", Some(CardBrand::UnionPay));
+            }
+
+            it "should return None for the empty sequence" {
+                test_case("", None);
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{CrateError, CrateResult, DigitSequence, IntoDigit};
+
+/// A [char] can be converted to a one-digit [DigitSequence],
+/// as long as it is an ASCII digit - otherwise, the result is
+/// a [CrateError::NonDigitChar].
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: DigitSequence = '7'.try_into()?;
+/// assert_eq!(sequence, [7]);
+///
+/// let result: CrateResult<DigitSequence> = 'x'.try_into();
+/// assert_eq!(result, Err(CrateError::NonDigitChar('x')));
+///
+/// # Ok(())
+/// # }
+/// ```
+impl TryFrom<char> for DigitSequence {
+    type Error = CrateError;
+
+    fn try_from(value: char) -> CrateResult<Self> {
+        DigitSequence::try_from_iter([value.into_digit()?])
+    }
+}
+
+/// A slice of [char] can be converted to [DigitSequence], as long as
+/// it only contains ASCII digits - otherwise, the result is a
+/// [CrateError::NonDigitChar] for the first invalid char.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let chars: &[char] = &['0', '3', '9'];
+/// let sequence: DigitSequence = chars.try_into()?;
+/// assert_eq!(sequence, [0, 3, 9]);
+///
+/// let chars: &[char] = &['0', '-', '9'];
+/// let result: CrateResult<DigitSequence> = chars.try_into();
+/// assert_eq!(result, Err(CrateError::NonDigitChar('-')));
+///
+/// # Ok(())
+/// # }
+/// ```
+impl TryFrom<&[char]> for DigitSequence {
+    type Error = CrateError;
+
+    fn try_from(values: &[char]) -> CrateResult<Self> {
+        DigitSequence::try_from_iter(values)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{DigitSequence, DigitSlice};
+
+const QUASIGROUP: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+pub(crate) fn damm_interim(digits: impl Iterator<Item = u8>) -> u8 {
+    digits.fold(0, |interim, digit| {
+        QUASIGROUP[interim as usize][digit as usize]
+    })
+}
+
+impl DigitSequence {
+    /// Tells whether the sequence - payload followed by check digit - satisfies
+    /// the [Damm algorithm](https://en.wikipedia.org/wiki/Damm_algorithm),
+    /// which detects all single-digit errors and all adjacent transpositions.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let valid: DigitSequence = 5724u16.into();
+    /// assert!(valid.damm_is_valid());
+    ///
+    /// let transposed: DigitSequence = 7524u16.into();
+    /// assert!(!transposed.damm_is_valid());
+    ///
+    /// assert!(!DigitSequence::from(0u8).damm_is_valid());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn damm_is_valid(&self) -> bool {
+        self.as_digit_slice().damm_is_valid()
+    }
+
+    /// Computes the [Damm](https://en.wikipedia.org/wiki/Damm_algorithm)
+    /// check digit for the sequence, considered as a payload.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let payload: DigitSequence = 572u16.into();
+    /// assert_eq!(payload.damm_check_digit(), 4);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn damm_check_digit(&self) -> u8 {
+        self.as_digit_slice().damm_check_digit()
+    }
+}
+
+impl DigitSlice<'_> {
+    /// Just like [DigitSequence::damm_is_valid].
+    pub fn damm_is_valid(&self) -> bool {
+        self.0.len() >= 2 && damm_interim(self.0.iter().copied()) == 0
+    }
+
+    /// Just like [DigitSequence::damm_check_digit].
+    pub fn damm_check_digit(&self) -> u8 {
+        damm_interim(self.0.iter().copied())
+    }
+}
@@ -0,0 +1,204 @@
+use crate::result::require_len;
+use crate::{CrateResult, DigitSequence, DigitSlice};
+
+/// The GS1 code formats supported by the GTIN check-digit algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GtinFormat {
+    /// EAN-8 (GTIN-8): 8 digits.
+    Ean8,
+
+    /// UPC-A (GTIN-12): 12 digits.
+    UpcA,
+
+    /// EAN-13 (GTIN-13): 13 digits.
+    Ean13,
+
+    /// GTIN-14: 14 digits.
+    Gtin14,
+}
+
+impl GtinFormat {
+    /// The number of digits - including the check digit - of a code in this format.
+    ///
+    /// ```
+    /// use digit_sequence::checksum::GtinFormat;
+    ///
+    /// assert_eq!(GtinFormat::Ean8.len(), 8);
+    /// assert_eq!(GtinFormat::UpcA.len(), 12);
+    /// assert_eq!(GtinFormat::Ean13.len(), 13);
+    /// assert_eq!(GtinFormat::Gtin14.len(), 14);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Ean8 => 8,
+            Self::UpcA => 12,
+            Self::Ean13 => 13,
+            Self::Gtin14 => 14,
+        }
+    }
+
+    /// The format of codes having the given number of digits, if supported.
+    ///
+    /// ```
+    /// use digit_sequence::checksum::GtinFormat;
+    ///
+    /// assert_eq!(GtinFormat::from_len(13), Some(GtinFormat::Ean13));
+    /// assert_eq!(GtinFormat::from_len(10), None);
+    /// ```
+    pub fn from_len(len: usize) -> Option<GtinFormat> {
+        match len {
+            8 => Some(Self::Ean8),
+            12 => Some(Self::UpcA),
+            13 => Some(Self::Ean13),
+            14 => Some(Self::Gtin14),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn gs1_weighted_sum(reversed_payload: impl Iterator<Item = u8>) -> u64 {
+    reversed_payload
+        .enumerate()
+        .map(|(index, digit)| if index % 2 == 0 { 3 } else { 1 } * digit as u64)
+        .sum()
+}
+
+pub(crate) fn gs1_check_digit(payload: &[u8]) -> u8 {
+    ((10 - gs1_weighted_sum(payload.iter().rev().copied()) % 10) % 10) as u8
+}
+
+/// Computes the GS1 check digit in a single left-to-right pass - just like
+/// the Luhn counterpart, by summing both weight patterns.
+pub(crate) fn gs1_check_digit_streamed(payload: impl Iterator<Item = u8>) -> u8 {
+    let mut sums = [0u64; 2];
+    let mut len = 0usize;
+
+    for digit in payload {
+        let parity = len % 2;
+
+        sums[parity] += 3 * digit as u64;
+        sums[1 - parity] += digit as u64;
+        len += 1;
+    }
+
+    let sum = if len == 0 { 0 } else { sums[(len - 1) % 2] };
+
+    ((10 - sum % 10) % 10) as u8
+}
+
+impl DigitSequence {
+    /// Computes the GS1 mod-10 check digit for a payload of the given format -
+    /// that is, having one digit less than the full code.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use digit_sequence::checksum::GtinFormat;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let ean13_payload: DigitSequence = "400638133393".parse()?;
+    /// assert_eq!(ean13_payload.gtin_check_digit(GtinFormat::Ean13)?, 1);
+    ///
+    /// let upc_payload: DigitSequence = "03600029145".parse()?;
+    /// assert_eq!(upc_payload.gtin_check_digit(GtinFormat::UpcA)?, 2);
+    ///
+    /// let ean8_payload: DigitSequence = "9638507".parse()?;
+    /// assert_eq!(ean8_payload.gtin_check_digit(GtinFormat::Ean8)?, 4);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// A payload whose length does not match the format results in a [CrateError::LengthMismatch](crate::CrateError::LengthMismatch):
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use digit_sequence::checksum::GtinFormat;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let payload: DigitSequence = "9638507".parse()?;
+    ///
+    /// assert_eq!(
+    ///     payload.gtin_check_digit(GtinFormat::Gtin14),
+    ///     Err(CrateError::LengthMismatch { expected: 13, actual: 7 })
+    /// );
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gtin_check_digit(&self, format: GtinFormat) -> CrateResult<u8> {
+        self.as_digit_slice().gtin_check_digit(format)
+    }
+
+    /// The GS1 format matching the length of the sequence, if supported.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use digit_sequence::checksum::GtinFormat;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let code: DigitSequence = "96385074".parse()?;
+    /// assert_eq!(code.gtin_format(), Some(GtinFormat::Ean8));
+    ///
+    /// let code: DigitSequence = "963850".parse()?;
+    /// assert_eq!(code.gtin_format(), None);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gtin_format(&self) -> Option<GtinFormat> {
+        self.as_digit_slice().gtin_format()
+    }
+
+    /// Tells whether the sequence is a valid EAN-8, UPC-A, EAN-13 or GTIN-14 code;
+    /// sequences of any other length are never valid.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let ean13: DigitSequence = "4006381333931".parse()?;
+    /// assert!(ean13.gtin_is_valid());
+    ///
+    /// let upc: DigitSequence = "036000291452".parse()?;
+    /// assert!(upc.gtin_is_valid());
+    ///
+    /// let typo: DigitSequence = "036000291453".parse()?;
+    /// assert!(!typo.gtin_is_valid());
+    ///
+    /// let unsupported_length: DigitSequence = "0360002914".parse()?;
+    /// assert!(!unsupported_length.gtin_is_valid());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gtin_is_valid(&self) -> bool {
+        self.as_digit_slice().gtin_is_valid()
+    }
+}
+
+impl DigitSlice<'_> {
+    /// Just like [DigitSequence::gtin_check_digit].
+    pub fn gtin_check_digit(&self, format: GtinFormat) -> CrateResult<u8> {
+        let expected = format.len() - 1;
+
+        require_len(self.0.len(), expected..=expected)?;
+
+        Ok(gs1_check_digit(self.0))
+    }
+
+    /// Just like [DigitSequence::gtin_format].
+    pub fn gtin_format(&self) -> Option<GtinFormat> {
+        GtinFormat::from_len(self.0.len())
+    }
+
+    /// Just like [DigitSequence::gtin_is_valid].
+    pub fn gtin_is_valid(&self) -> bool {
+        match self.0.split_last() {
+            Some((&check_digit, payload)) if self.gtin_format().is_some() => {
+                gs1_check_digit(payload) == check_digit
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{DigitSequence, DigitSlice};
+
+impl DigitSequence {
+    /// The ISO 7064 MOD 97-10 remainder of the represented value, computed
+    /// digit by digit - so that sequences of any length are supported.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 1000u16.into();
+    /// assert_eq!(sequence.mod97(), 30);
+    ///
+    /// let huge: DigitSequence = "9".repeat(200).parse()?;
+    /// assert_eq!(huge.mod97(), 80);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mod97(&self) -> u8 {
+        self.as_digit_slice().mod97()
+    }
+
+    /// Tells whether the sequence is a valid *rearranged* IBAN - that is,
+    /// an IBAN whose first 4 characters have been moved to the end and
+    /// whose letters have been replaced by 2-digit numbers (`A` = 10, ..., `Z` = 35):
+    /// such a sequence is valid when its [mod97](DigitSequence::mod97) is 1.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// // GB82 WEST 1234 5698 7654 32
+    /// let rearranged_iban: DigitSequence = "3214282912345698765432161182".parse()?;
+    /// assert!(rearranged_iban.iban_is_valid_rearranged());
+    ///
+    /// // GB82 WEST 1234 5698 7654 23
+    /// let typo: DigitSequence = "3214282912345698765423161182".parse()?;
+    /// assert!(!typo.iban_is_valid_rearranged());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iban_is_valid_rearranged(&self) -> bool {
+        self.as_digit_slice().iban_is_valid_rearranged()
+    }
+}
+
+impl DigitSlice<'_> {
+    /// Just like [DigitSequence::mod97].
+    pub fn mod97(&self) -> u8 {
+        self.remainder(97) as u8
+    }
+
+    /// Just like [DigitSequence::iban_is_valid_rearranged].
+    pub fn iban_is_valid_rearranged(&self) -> bool {
+        self.mod97() == 1
+    }
+}
@@ -0,0 +1,184 @@
+use super::gtin::gs1_check_digit;
+use crate::result::require_len;
+use crate::{CrateResult, DigitSequence, DigitSlice};
+use core::fmt::Display;
+
+/// The check digit of an ISBN-10 code, which can also be `X` - standing for 10.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Isbn10CheckDigit {
+    /// A 0-9 check digit.
+    Digit(u8),
+
+    /// The `X` check digit, standing for 10.
+    X,
+}
+
+/// [Isbn10CheckDigit] is displayed just as it appears in an ISBN-10 code.
+///
+/// ```
+/// use digit_sequence::checksum::Isbn10CheckDigit;
+///
+/// assert_eq!(Isbn10CheckDigit::Digit(7).to_string(), "7");
+/// assert_eq!(Isbn10CheckDigit::X.to_string(), "X");
+/// ```
+impl Display for Isbn10CheckDigit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Digit(digit) => write!(f, "{}", digit),
+            Self::X => write!(f, "X"),
+        }
+    }
+}
+
+fn isbn10_weighted_sum(digits: &[u8]) -> u64 {
+    digits
+        .iter()
+        .enumerate()
+        .map(|(index, &digit)| (index as u64 + 1) * digit as u64)
+        .sum()
+}
+
+impl DigitSequence {
+    /// Computes the ISBN-10 check digit for a 9-digit payload.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use digit_sequence::checksum::Isbn10CheckDigit;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let payload: DigitSequence = "030640615".parse()?;
+    /// assert_eq!(payload.isbn10_check_digit()?, Isbn10CheckDigit::Digit(2));
+    ///
+    /// let payload: DigitSequence = "080442957".parse()?;
+    /// assert_eq!(payload.isbn10_check_digit()?, Isbn10CheckDigit::X);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Any other payload length results in a [CrateError::LengthMismatch](crate::CrateError::LengthMismatch):
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let payload: DigitSequence = 90u8.into();
+    ///
+    /// assert_eq!(
+    ///     payload.isbn10_check_digit(),
+    ///     Err(CrateError::LengthMismatch { expected: 9, actual: 2 })
+    /// );
+    /// ```
+    pub fn isbn10_check_digit(&self) -> CrateResult<Isbn10CheckDigit> {
+        self.as_digit_slice().isbn10_check_digit()
+    }
+
+    /// Tells whether the sequence is a valid ISBN-10 code - made of 10 digits.
+    ///
+    /// Since a [DigitSequence] cannot contain `X`, codes whose check digit is `X`
+    /// must be validated by comparing
+    /// [isbn10_check_digit](DigitSequence::isbn10_check_digit) on their payload.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let isbn: DigitSequence = "0306406152".parse()?;
+    /// assert!(isbn.isbn10_is_valid());
+    ///
+    /// let typo: DigitSequence = "0306406153".parse()?;
+    /// assert!(!typo.isbn10_is_valid());
+    ///
+    /// let too_short: DigitSequence = "030640615".parse()?;
+    /// assert!(!too_short.isbn10_is_valid());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn isbn10_is_valid(&self) -> bool {
+        self.as_digit_slice().isbn10_is_valid()
+    }
+
+    /// Computes the ISBN-13 check digit for a 12-digit payload - which
+    /// is the GS1 check digit of the corresponding EAN-13 code.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let payload: DigitSequence = "978030640615".parse()?;
+    /// assert_eq!(payload.isbn13_check_digit()?, 7);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Any other payload length results in a [CrateError::LengthMismatch](crate::CrateError::LengthMismatch):
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let payload: DigitSequence = 90u8.into();
+    ///
+    /// assert_eq!(
+    ///     payload.isbn13_check_digit(),
+    ///     Err(CrateError::LengthMismatch { expected: 12, actual: 2 })
+    /// );
+    /// ```
+    pub fn isbn13_check_digit(&self) -> CrateResult<u8> {
+        self.as_digit_slice().isbn13_check_digit()
+    }
+
+    /// Tells whether the sequence is a valid ISBN-13 code - made of 13 digits.
+    ///
+    /// ISBN-13 codes are EAN-13 codes, so this is equivalent to
+    /// [gtin_is_valid](DigitSequence::gtin_is_valid) restricted to 13 digits.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let isbn: DigitSequence = "9780306406157".parse()?;
+    /// assert!(isbn.isbn13_is_valid());
+    ///
+    /// let typo: DigitSequence = "9780306406158".parse()?;
+    /// assert!(!typo.isbn13_is_valid());
+    ///
+    /// let too_short: DigitSequence = "978030640615".parse()?;
+    /// assert!(!too_short.isbn13_is_valid());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn isbn13_is_valid(&self) -> bool {
+        self.as_digit_slice().isbn13_is_valid()
+    }
+}
+
+impl DigitSlice<'_> {
+    /// Just like [DigitSequence::isbn10_check_digit].
+    pub fn isbn10_check_digit(&self) -> CrateResult<Isbn10CheckDigit> {
+        require_len(self.0.len(), 9..=9)?;
+
+        Ok(match isbn10_weighted_sum(self.0) % 11 {
+            10 => Isbn10CheckDigit::X,
+            check_digit => Isbn10CheckDigit::Digit(check_digit as u8),
+        })
+    }
+
+    /// Just like [DigitSequence::isbn10_is_valid].
+    pub fn isbn10_is_valid(&self) -> bool {
+        self.0.len() == 10 && isbn10_weighted_sum(self.0).is_multiple_of(11)
+    }
+
+    /// Just like [DigitSequence::isbn13_check_digit].
+    pub fn isbn13_check_digit(&self) -> CrateResult<u8> {
+        require_len(self.0.len(), 12..=12)?;
+
+        Ok(gs1_check_digit(self.0))
+    }
+
+    /// Just like [DigitSequence::isbn13_is_valid].
+    pub fn isbn13_is_valid(&self) -> bool {
+        self.0.len() == 13 && self.gtin_is_valid()
+    }
+}
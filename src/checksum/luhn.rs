@@ -0,0 +1,141 @@
+use crate::{DigitSequence, DigitSlice};
+
+fn luhn_sum<'a, I>(reversed_digits: I, double_first: bool) -> u64
+where
+    I: Iterator<Item = &'a u8>,
+{
+    reversed_digits
+        .enumerate()
+        .map(|(index, &digit)| {
+            let must_double = (index % 2 == 0) == double_first;
+
+            match must_double {
+                true if digit >= 5 => (digit * 2 - 9) as u64,
+                true => (digit * 2) as u64,
+                false => digit as u64,
+            }
+        })
+        .sum()
+}
+
+/// Computes the Luhn check digit in a single left-to-right pass, without
+/// knowing the payload length in advance: both doubling patterns are summed,
+/// and the one doubling the rightmost digit is eventually chosen.
+pub(crate) fn luhn_check_digit_streamed(payload: impl Iterator<Item = u8>) -> u8 {
+    let mut sums = [0u64; 2];
+    let mut len = 0usize;
+
+    for digit in payload {
+        let doubled = if digit >= 5 { digit * 2 - 9 } else { digit * 2 } as u64;
+        let parity = len % 2;
+
+        sums[parity] += doubled;
+        sums[1 - parity] += digit as u64;
+        len += 1;
+    }
+
+    let sum = if len == 0 { 0 } else { sums[(len - 1) % 2] };
+
+    ((10 - sum % 10) % 10) as u8
+}
+
+impl DigitSequence {
+    /// Tells whether the sequence - payload followed by check digit - satisfies
+    /// the [Luhn algorithm](https://en.wikipedia.org/wiki/Luhn_algorithm),
+    /// used by credit card numbers and IMEI codes.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let card_number: DigitSequence = "4111111111111111".parse()?;
+    /// assert!(card_number.luhn_is_valid());
+    ///
+    /// let typo: DigitSequence = "4111111111111121".parse()?;
+    /// assert!(!typo.luhn_is_valid());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Sequences having less than 2 digits are never valid:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// assert!(!DigitSequence::new().luhn_is_valid());
+    /// assert!(!DigitSequence::from(0u8).luhn_is_valid());
+    /// ```
+    pub fn luhn_is_valid(&self) -> bool {
+        self.as_digit_slice().luhn_is_valid()
+    }
+
+    /// Computes the [Luhn](https://en.wikipedia.org/wiki/Luhn_algorithm)
+    /// check digit for the sequence, considered as a payload.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let payload: DigitSequence = "7992739871".parse()?;
+    /// assert_eq!(payload.luhn_check_digit(), 3);
+    ///
+    /// let imei_payload: DigitSequence = "49015420323751".parse()?;
+    /// assert_eq!(imei_payload.luhn_check_digit(), 8);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn luhn_check_digit(&self) -> u8 {
+        self.as_digit_slice().luhn_check_digit()
+    }
+}
+
+impl DigitSlice<'_> {
+    /// Just like [DigitSequence::luhn_is_valid].
+    pub fn luhn_is_valid(&self) -> bool {
+        self.0.len() >= 2 && luhn_sum(self.0.iter().rev(), false) % 10 == 0
+    }
+
+    /// Just like [DigitSequence::luhn_check_digit].
+    pub fn luhn_check_digit(&self) -> u8 {
+        ((10 - luhn_sum(self.0.iter().rev(), true) % 10) % 10) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Luhn algorithm" {
+            it "should validate the payload followed by its check digit" {
+                for payload in ["0", "1", "12", "378282246310005", "601111111111111", "35301113333000"] {
+                    let payload: DigitSequence = payload.parse().unwrap();
+
+                    let mut digits = payload.0.clone();
+                    digits.push(payload.luhn_check_digit());
+
+                    assert!(DigitSequence(digits).luhn_is_valid());
+                }
+            }
+
+            it "should reject every other check digit" {
+                let payload: DigitSequence = "7992739871".parse().unwrap();
+
+                let valid_check_digits: Vec<u8> = (0..10)
+                    .filter(|&check_digit| {
+                        let mut digits = payload.0.clone();
+                        digits.push(check_digit);
+
+                        DigitSequence(digits).luhn_is_valid()
+                    })
+                    .collect();
+
+                eq!(valid_check_digits, vec![3]);
+            }
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//! Check-digit algorithms, available as methods of [DigitSequence].
+//!
+//! In all the algorithms, a *valid* sequence consists of a non-empty *payload*
+//! followed by its check digit; conversely, check-digit computation
+//! is always performed on the payload alone.
+//!
+//! The algorithms producing a single 0-9 check digit also implement
+//! the [Checksum] trait, so that they can be used interchangeably.
+
+mod damm;
+mod gtin;
+mod iban;
+mod isbn;
+mod luhn;
+mod verhoeff;
+
+pub use gtin::*;
+pub use isbn::*;
+
+use crate::{CrateResult, DigitSequence, IntoDigit};
+
+/// Check-digit algorithm producing a single 0-9 check digit.
+///
+/// Only [check_digit](Checksum::check_digit) must be implemented,
+/// which makes it easy to plug in custom schemes:
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::checksum::*;
+///
+/// struct DigitSumMod10;
+///
+/// impl Checksum for DigitSumMod10 {
+///     fn check_digit(&self, payload: &DigitSequence) -> u8 {
+///         (payload.digit_sum() % 10) as u8
+///     }
+/// }
+///
+/// fn validate_all(checksum: &impl Checksum, sequences: &[DigitSequence]) -> bool {
+///     sequences.iter().all(|sequence| checksum.is_valid(sequence))
+/// }
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequences: Vec<DigitSequence> = vec![3474u16.into(), 9818u16.into()];
+///
+/// assert!(validate_all(&DigitSumMod10, &sequences));
+/// assert!(!validate_all(&Luhn, &sequences));
+///
+/// # Ok(())
+/// # }
+/// ```
+pub trait Checksum {
+    /// Computes the check digit for the given payload.
+    fn check_digit(&self, payload: &DigitSequence) -> u8;
+
+    /// Tells whether the given sequence - payload followed by check digit - is valid;
+    /// by default, sequences having less than 2 digits are never valid.
+    fn is_valid(&self, sequence: &DigitSequence) -> bool {
+        match sequence.0.split_last() {
+            Some((&check_digit, payload)) if !payload.is_empty() => {
+                self.check_digit(&DigitSequence(payload.into())) == check_digit
+            }
+            _ => false,
+        }
+    }
+
+    /// Computes the check digit for the payload yielded by the given iterator -
+    /// or returns a [CrateError::NonDigitNumber](crate::CrateError::NonDigitNumber) for the first item that
+    /// is not a 0-9 digit.
+    ///
+    /// By default, the payload is validated and collected into a [DigitSequence];
+    /// the built-in algorithms that can work in a single pass - [Luhn], [Damm]
+    /// and [Gs1] - require no allocation instead.
+    fn fold_check_digit(&self, payload: impl Iterator<Item = u8>) -> CrateResult<u8>
+    where
+        Self: Sized,
+    {
+        let payload = DigitSequence::try_from_iter(payload)?;
+
+        Ok(self.check_digit(&payload))
+    }
+}
+
+/// Feeds the given payload to a single-pass check-digit algorithm - stopping
+/// at the first item that is not a 0-9 digit, whose error is returned.
+fn fold_digits(
+    payload: impl Iterator<Item = u8>,
+    fold: impl FnOnce(&mut dyn Iterator<Item = u8>) -> u8,
+) -> CrateResult<u8> {
+    let mut error = None;
+
+    let check_digit = {
+        let mut digits = payload.map_while(|item| match item.into_digit() {
+            Ok(digit) => Some(digit),
+            Err(err) => {
+                error = Some(err);
+                None
+            }
+        });
+
+        fold(&mut digits)
+    };
+
+    match error {
+        Some(err) => Err(err),
+        None => Ok(check_digit),
+    }
+}
+
+/// The [Luhn](https://en.wikipedia.org/wiki/Luhn_algorithm) algorithm
+/// as a [Checksum].
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::checksum::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let payload: DigitSequence = "7992739871".parse()?;
+/// assert_eq!(Luhn.check_digit(&payload), 3);
+///
+/// let sequence: DigitSequence = "79927398713".parse()?;
+/// assert!(Luhn.is_valid(&sequence));
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Luhn;
+
+impl Checksum for Luhn {
+    fn check_digit(&self, payload: &DigitSequence) -> u8 {
+        payload.luhn_check_digit()
+    }
+
+    fn is_valid(&self, sequence: &DigitSequence) -> bool {
+        sequence.luhn_is_valid()
+    }
+
+    fn fold_check_digit(&self, payload: impl Iterator<Item = u8>) -> CrateResult<u8> {
+        fold_digits(payload, |digits| luhn::luhn_check_digit_streamed(digits))
+    }
+}
+
+/// The [Verhoeff](https://en.wikipedia.org/wiki/Verhoeff_algorithm) algorithm
+/// as a [Checksum].
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::checksum::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let payload: DigitSequence = 236u16.into();
+/// assert_eq!(Verhoeff.check_digit(&payload), 3);
+///
+/// let sequence: DigitSequence = 2363u16.into();
+/// assert!(Verhoeff.is_valid(&sequence));
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Verhoeff;
+
+impl Checksum for Verhoeff {
+    fn check_digit(&self, payload: &DigitSequence) -> u8 {
+        payload.verhoeff_check_digit()
+    }
+
+    fn is_valid(&self, sequence: &DigitSequence) -> bool {
+        sequence.verhoeff_is_valid()
+    }
+}
+
+/// The [Damm](https://en.wikipedia.org/wiki/Damm_algorithm) algorithm
+/// as a [Checksum].
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::checksum::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let payload: DigitSequence = 572u16.into();
+/// assert_eq!(Damm.check_digit(&payload), 4);
+///
+/// let sequence: DigitSequence = 5724u16.into();
+/// assert!(Damm.is_valid(&sequence));
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Damm;
+
+impl Checksum for Damm {
+    fn check_digit(&self, payload: &DigitSequence) -> u8 {
+        payload.damm_check_digit()
+    }
+
+    fn is_valid(&self, sequence: &DigitSequence) -> bool {
+        sequence.damm_is_valid()
+    }
+
+    fn fold_check_digit(&self, payload: impl Iterator<Item = u8>) -> CrateResult<u8> {
+        fold_digits(payload, |digits| damm::damm_interim(digits))
+    }
+}
+
+/// The GS1 mod-10 algorithm - used by EAN, UPC, GTIN and ISBN-13 codes -
+/// as a [Checksum].
+///
+/// Unlike [DigitSequence::gtin_check_digit], this implementation
+/// accepts payloads of any length.
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::checksum::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let payload: DigitSequence = "400638133393".parse()?;
+/// assert_eq!(Gs1.check_digit(&payload), 1);
+///
+/// let sequence: DigitSequence = "4006381333931".parse()?;
+/// assert!(Gs1.is_valid(&sequence));
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Gs1;
+
+impl Checksum for Gs1 {
+    fn check_digit(&self, payload: &DigitSequence) -> u8 {
+        gtin::gs1_check_digit(&payload.0)
+    }
+
+    fn fold_check_digit(&self, payload: impl Iterator<Item = u8>) -> CrateResult<u8> {
+        fold_digits(payload, |digits| gtin::gs1_check_digit_streamed(digits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CrateError;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "The default Checksum validation" {
+            fn default_is_valid(checksum: &impl Checksum, sequence: &str) -> bool {
+                struct DefaultValidation<'a, C: Checksum>(&'a C);
+
+                impl<C: Checksum> Checksum for DefaultValidation<'_, C> {
+                    fn check_digit(&self, payload: &DigitSequence) -> u8 {
+                        self.0.check_digit(payload)
+                    }
+                }
+
+                DefaultValidation(checksum).is_valid(&sequence.parse().unwrap())
+            }
+
+            it "should agree with the dedicated validators" {
+                for sequence in ["79927398713", "79927398710", "2363", "3263", "5724", "7524"] {
+                    let parsed: DigitSequence = sequence.parse().unwrap();
+
+                    eq!(default_is_valid(&Luhn, sequence), parsed.luhn_is_valid());
+                    eq!(default_is_valid(&Verhoeff, sequence), parsed.verhoeff_is_valid());
+                    eq!(default_is_valid(&Damm, sequence), parsed.damm_is_valid());
+                }
+            }
+
+            it "should reject sequences shorter than 2 digits" {
+                assert!(!default_is_valid(&Damm, ""));
+                assert!(!default_is_valid(&Damm, "0"));
+            }
+        }
+
+        describe "Folding a check digit" {
+            fn assert_folds_like_check_digit(checksum: &impl Checksum) {
+                for payload in ["", "0", "7", "12", "7992739871", "400638133393", "49015420323751"] {
+                    let payload: DigitSequence = payload.parse().unwrap();
+
+                    eq!(
+                        checksum.fold_check_digit(payload.iter().copied()),
+                        Ok(checksum.check_digit(&payload))
+                    );
+                }
+            }
+
+            fn assert_rejects_non_digits(checksum: &impl Checksum) {
+                for item in [10, 12, 127, 128, 255] {
+                    eq!(
+                        checksum.fold_check_digit([4, 7, item, 1].into_iter()),
+                        Err(CrateError::NonDigitNumber(item as u128))
+                    );
+                }
+            }
+
+            it "should match the check digit of every algorithm" {
+                assert_folds_like_check_digit(&Luhn);
+                assert_folds_like_check_digit(&Verhoeff);
+                assert_folds_like_check_digit(&Damm);
+                assert_folds_like_check_digit(&Gs1);
+            }
+
+            it "should reject non-digit items by default" {
+                struct DefaultFold;
+
+                impl Checksum for DefaultFold {
+                    fn check_digit(&self, payload: &DigitSequence) -> u8 {
+                        payload.iter().len() as u8
+                    }
+                }
+
+                eq!(DefaultFold.fold_check_digit([1, 2, 3].into_iter()), Ok(3));
+                assert_rejects_non_digits(&DefaultFold);
+            }
+
+            it "should reject non-digit items in every algorithm" {
+                assert_rejects_non_digits(&Luhn);
+                assert_rejects_non_digits(&Verhoeff);
+                assert_rejects_non_digits(&Damm);
+                assert_rejects_non_digits(&Gs1);
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{DigitSequence, DigitSlice};
+
+const MULTIPLICATION: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+const PERMUTATION: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+const INVERSE: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+fn verhoeff_checksum(digits: &[u8], first_position: usize) -> u8 {
+    digits
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0, |checksum, (index, &digit)| {
+            let permuted = PERMUTATION[(index + first_position) % 8][digit as usize];
+
+            MULTIPLICATION[checksum as usize][permuted as usize]
+        })
+}
+
+impl DigitSequence {
+    /// Tells whether the sequence - payload followed by check digit - satisfies
+    /// the [Verhoeff algorithm](https://en.wikipedia.org/wiki/Verhoeff_algorithm),
+    /// which detects all single-digit errors and all adjacent transpositions.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let valid: DigitSequence = 2363u16.into();
+    /// assert!(valid.verhoeff_is_valid());
+    ///
+    /// let transposed: DigitSequence = 3263u16.into();
+    /// assert!(!transposed.verhoeff_is_valid());
+    ///
+    /// assert!(!DigitSequence::from(0u8).verhoeff_is_valid());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verhoeff_is_valid(&self) -> bool {
+        self.as_digit_slice().verhoeff_is_valid()
+    }
+
+    /// Computes the [Verhoeff](https://en.wikipedia.org/wiki/Verhoeff_algorithm)
+    /// check digit for the sequence, considered as a payload.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let payload: DigitSequence = 236u16.into();
+    /// assert_eq!(payload.verhoeff_check_digit(), 3);
+    ///
+    /// let payload: DigitSequence = 12345u16.into();
+    /// assert_eq!(payload.verhoeff_check_digit(), 1);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verhoeff_check_digit(&self) -> u8 {
+        self.as_digit_slice().verhoeff_check_digit()
+    }
+}
+
+impl DigitSlice<'_> {
+    /// Just like [DigitSequence::verhoeff_is_valid].
+    pub fn verhoeff_is_valid(&self) -> bool {
+        self.0.len() >= 2 && verhoeff_checksum(self.0, 0) == 0
+    }
+
+    /// Just like [DigitSequence::verhoeff_check_digit].
+    pub fn verhoeff_check_digit(&self) -> u8 {
+        INVERSE[verhoeff_checksum(self.0, 1) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speculate2::*;
+
+    speculate! {
+        describe "Verhoeff algorithm" {
+            it "should detect all the adjacent transpositions" {
+                let payload: DigitSequence = "8473643095".parse().unwrap();
+
+                let mut digits = payload.0.clone();
+                digits.push(payload.verhoeff_check_digit());
+
+                assert!(DigitSequence(digits.clone()).verhoeff_is_valid());
+
+                for index in 0..digits.len() - 1 {
+                    if digits[index] == digits[index + 1] {
+                        continue;
+                    }
+
+                    let mut transposed = digits.clone();
+                    transposed.swap(index, index + 1);
+
+                    assert!(!DigitSequence(transposed).verhoeff_is_valid());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use crate::{CrateError, CrateResult, DigitSequence, DigitSlice};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::iter::FusedIterator;
+use core::ops::{Bound, RangeBounds};
+use core::str::FromStr;
+
+/// Default number of digits per chunk of [ChunkedDigitSequence] - 1 MiB.
+pub const DEFAULT_CHUNK_LEN: usize = 1 << 20;
+
+/// Sequence of digits stored in a list of chunks having `CHUNK_LEN` digits each -
+/// except the last one, which might be shorter: this way, appending never
+/// moves the existing digits, and hundreds of millions of digits never
+/// require a single, gigantic contiguous allocation.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let mut sequence: ChunkedDigitSequence<4> = "31415".parse()?;
+///
+/// sequence.push(9)?;
+/// sequence.append(&DigitSequence::from(2653u16));
+///
+/// assert_eq!(sequence.len(), 10);
+/// assert_eq!(sequence.to_string(), "3141592653");
+/// assert_eq!(sequence.get(5), Some(9));
+///
+/// let chunks: Vec<String> = sequence.chunks().map(|chunk| chunk.to_string()).collect();
+/// assert_eq!(chunks, ["3141", "5926", "53"]);
+///
+/// let middle: DigitSequence = sequence.slice(3..7).unwrap().collect::<Vec<u8>>().try_into()?;
+/// assert_eq!(middle, [1, 5, 9, 2]);
+///
+/// let contiguous: DigitSequence = (&sequence).into();
+/// assert_eq!(contiguous, [3, 1, 4, 1, 5, 9, 2, 6, 5, 3]);
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The default chunk length is [DEFAULT_CHUNK_LEN]:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: ChunkedDigitSequence = "9".repeat(3_000_000).parse()?;
+///
+/// assert_eq!(sequence.chunks().count(), 3);
+/// assert!(sequence.iter().all(|digit| digit == 9));
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChunkedDigitSequence<const CHUNK_LEN: usize = DEFAULT_CHUNK_LEN> {
+    chunks: Vec<Vec<u8>>,
+    len: usize,
+}
+
+impl<const CHUNK_LEN: usize> ChunkedDigitSequence<CHUNK_LEN> {
+    /// Creates an empty sequence.
+    ///
+    /// # Panics
+    ///
+    /// If `CHUNK_LEN` is 0.
+    pub fn new() -> Self {
+        assert!(CHUNK_LEN > 0, "The chunk length must be positive");
+
+        ChunkedDigitSequence {
+            chunks: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Number of digits in the sequence.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Tells whether the sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The digit at the given index, if any.
+    pub fn get(&self, index: usize) -> Option<u8> {
+        if index < self.len {
+            Some(self.chunks[index / CHUNK_LEN][index % CHUNK_LEN])
+        } else {
+            None
+        }
+    }
+
+    /// Appends a single digit - which must be in the 0-9 range,
+    /// otherwise the result is a [CrateError::NonDigitNumber].
+    pub fn push(&mut self, digit: u8) -> CrateResult<()> {
+        if digit >= 10 {
+            return Err(CrateError::NonDigitNumber(digit as u128));
+        }
+
+        self.push_unchecked(digit);
+
+        Ok(())
+    }
+
+    /// Appends all the digits of the given sequence - or of anything
+    /// that can be viewed as a [DigitSlice].
+    pub fn append<'a>(&mut self, digits: impl Into<DigitSlice<'a>>) {
+        let mut remaining = digits.into().as_slice();
+
+        while !remaining.is_empty() {
+            let chunk = self.writable_chunk();
+            let taken = remaining.len().min(CHUNK_LEN - chunk.len());
+
+            chunk.extend_from_slice(&remaining[..taken]);
+            remaining = &remaining[taken..];
+            self.len += taken;
+        }
+    }
+
+    /// Iterates over the digits.
+    pub fn iter(&self) -> ChunkedDigits<'_, CHUNK_LEN> {
+        ChunkedDigits {
+            sequence: self,
+            front: 0,
+            back: self.len,
+        }
+    }
+
+    /// Iterates over the digits within the given range - or returns [None]
+    /// if the range is out of bounds.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: ChunkedDigitSequence<2> = "90781".parse()?;
+    ///
+    /// assert_eq!(sequence.slice(1..4).unwrap().collect::<Vec<u8>>(), [0, 7, 8]);
+    /// assert_eq!(sequence.slice(3..).unwrap().rev().collect::<Vec<u8>>(), [1, 8]);
+    /// assert!(sequence.slice(4..6).is_none());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Option<ChunkedDigits<'_, CHUNK_LEN>> {
+        let front = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+
+        let back = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+
+        if front > back || back > self.len {
+            return None;
+        }
+
+        Some(ChunkedDigits {
+            sequence: self,
+            front,
+            back,
+        })
+    }
+
+    /// Iterates over the chunks, each viewed as a [DigitSlice].
+    pub fn chunks(&self) -> impl DoubleEndedIterator<Item = DigitSlice<'_>> + ExactSizeIterator {
+        self.chunks.iter().map(|chunk| DigitSlice(chunk))
+    }
+
+    fn push_unchecked(&mut self, digit: u8) {
+        self.writable_chunk().push(digit);
+        self.len += 1;
+    }
+
+    fn writable_chunk(&mut self) -> &mut Vec<u8> {
+        if self.len.is_multiple_of(CHUNK_LEN) {
+            self.chunks.push(Vec::with_capacity(CHUNK_LEN));
+        }
+
+        self.chunks.last_mut().unwrap()
+    }
+}
+
+impl<const CHUNK_LEN: usize> Default for ChunkedDigitSequence<CHUNK_LEN> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the digits of a [ChunkedDigitSequence] - or of a slice of it.
+#[derive(Debug, Clone)]
+pub struct ChunkedDigits<'a, const CHUNK_LEN: usize> {
+    sequence: &'a ChunkedDigitSequence<CHUNK_LEN>,
+    front: usize,
+    back: usize,
+}
+
+impl<const CHUNK_LEN: usize> Iterator for ChunkedDigits<'_, CHUNK_LEN> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let digit = self.sequence.get(self.front);
+        self.front += 1;
+
+        digit
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<const CHUNK_LEN: usize> DoubleEndedIterator for ChunkedDigits<'_, CHUNK_LEN> {
+    fn next_back(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        self.sequence.get(self.back)
+    }
+}
+
+impl<const CHUNK_LEN: usize> ExactSizeIterator for ChunkedDigits<'_, CHUNK_LEN> {}
+
+impl<const CHUNK_LEN: usize> FusedIterator for ChunkedDigits<'_, CHUNK_LEN> {}
+
+impl<'a, const CHUNK_LEN: usize> IntoIterator for &'a ChunkedDigitSequence<CHUNK_LEN> {
+    type Item = u8;
+
+    type IntoIter = ChunkedDigits<'a, CHUNK_LEN>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Ordering is the same as [DigitSequence] - that is, lexicographic.
+impl<const CHUNK_LEN: usize> PartialOrd for ChunkedDigitSequence<CHUNK_LEN> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const CHUNK_LEN: usize> Ord for ChunkedDigitSequence<CHUNK_LEN> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Conversion from a &[DigitSequence] copies the digits into chunks.
+impl<const CHUNK_LEN: usize> From<&DigitSequence> for ChunkedDigitSequence<CHUNK_LEN> {
+    fn from(sequence: &DigitSequence) -> Self {
+        let mut result = Self::new();
+        result.append(sequence);
+        result
+    }
+}
+
+/// Conversion from a [DigitSequence] copies the digits into chunks.
+impl<const CHUNK_LEN: usize> From<DigitSequence> for ChunkedDigitSequence<CHUNK_LEN> {
+    fn from(sequence: DigitSequence) -> Self {
+        (&sequence).into()
+    }
+}
+
+/// Conversion to [DigitSequence] copies all the digits into contiguous storage.
+impl<const CHUNK_LEN: usize> From<&ChunkedDigitSequence<CHUNK_LEN>> for DigitSequence {
+    fn from(sequence: &ChunkedDigitSequence<CHUNK_LEN>) -> DigitSequence {
+        let mut digits = crate::Digits::with_capacity(sequence.len);
+
+        for chunk in &sequence.chunks {
+            digits.extend_from_slice(chunk);
+        }
+
+        DigitSequence(digits)
+    }
+}
+
+/// Conversion to [DigitSequence] copies all the digits into contiguous storage.
+impl<const CHUNK_LEN: usize> From<ChunkedDigitSequence<CHUNK_LEN>> for DigitSequence {
+    fn from(sequence: ChunkedDigitSequence<CHUNK_LEN>) -> DigitSequence {
+        (&sequence).into()
+    }
+}
+
+/// Parsing stores the ASCII digits directly into chunks, without an
+/// intermediate [DigitSequence]; any other character results in a
+/// [CrateError::NonDigitChar].
+impl<const CHUNK_LEN: usize> FromStr for ChunkedDigitSequence<CHUNK_LEN> {
+    type Err = CrateError;
+
+    fn from_str(s: &str) -> CrateResult<Self> {
+        let mut result = Self::new();
+
+        for current_char in s.chars() {
+            match current_char {
+                '0'..='9' => result.push_unchecked(current_char as u8 - b'0'),
+                _ => return Err(CrateError::NonDigitChar(current_char)),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// The string representation is the concatenation of the digits - written
+/// chunk by chunk, unless width, precision or alternate form are requested.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: ChunkedDigitSequence<2> = "1234567".parse()?;
+///
+/// assert_eq!(sequence.to_string(), "1234567");
+/// assert_eq!(format!("{:>9}", sequence), "  1234567");
+/// assert_eq!(format!("{:#}", sequence), "1 234 567");
+/// assert_eq!(format!("{:.3}", sequence), "123");
+///
+/// # Ok(())
+/// # }
+/// ```
+impl<const CHUNK_LEN: usize> Display for ChunkedDigitSequence<CHUNK_LEN> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.width().is_some() || f.precision().is_some() || f.alternate() {
+            return DigitSequence::from(self).fmt(f);
+        }
+
+        for chunk in self.chunks() {
+            chunk.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// [ChunkedDigitSequence] can be compared with a [DigitSequence].
+impl<const CHUNK_LEN: usize> PartialEq<DigitSequence> for ChunkedDigitSequence<CHUNK_LEN> {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        self.len == other.0.len() && self.iter().eq(other.iter().copied())
+    }
+}
+
+/// [DigitSequence] can be compared with a [ChunkedDigitSequence].
+impl<const CHUNK_LEN: usize> PartialEq<ChunkedDigitSequence<CHUNK_LEN>> for DigitSequence {
+    fn eq(&self, other: &ChunkedDigitSequence<CHUNK_LEN>) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Chunked digit sequence" {
+            it "should behave like DigitSequence across chunk boundaries" {
+                for sequence in short_sequences(13) {
+                    let length = sequence.iter().len();
+                    let chunked = ChunkedDigitSequence::<3>::from(&sequence);
+
+                    eq!(chunked.len(), length);
+                    eq!(chunked.chunks().count(), length.div_ceil(3));
+                    eq!(chunked.to_string(), sequence.to_string());
+                    eq!(chunked.iter().rev().collect::<Vec<u8>>(), sequence.iter().rev().copied().collect::<Vec<u8>>());
+                    eq!(DigitSequence::from(&chunked), sequence);
+                    eq!(chunked, sequence);
+                }
+            }
+
+            it "should apply precision to the whole sequence" {
+                let chunked: ChunkedDigitSequence<2> = "1234567".parse().unwrap();
+                let sequence = DigitSequence::from(&chunked);
+
+                for precision in 0..=8 {
+                    eq!(format!("{:.*}", precision, chunked), format!("{:.*}", precision, sequence));
+                }
+
+                eq!(format!("{:.1}", chunked), "1");
+                eq!(format!("{:.3}", chunked), "123");
+            }
+
+            it "should append across chunk boundaries" {
+                let mut chunked = ChunkedDigitSequence::<3>::new();
+
+                chunked.push(1).unwrap();
+                chunked.append(&DigitSequence::from(2345678u32));
+                chunked.push(9).unwrap();
+
+                eq!(chunked.to_string(), "123456789");
+                eq!(chunked.chunks().map(|chunk| chunk.len()).collect::<Vec<usize>>(), vec![3, 3, 3]);
+            }
+
+            it "should reject non-digit numbers" {
+                let mut chunked = ChunkedDigitSequence::<3>::new();
+
+                eq!(chunked.push(10), Err(CrateError::NonDigitNumber(10)));
+                assert!(chunked.is_empty());
+            }
+
+            it "should slice within bounds only" {
+                let chunked: ChunkedDigitSequence<3> = "0123456".parse().unwrap();
+
+                eq!(chunked.slice(..).unwrap().len(), 7);
+                eq!(chunked.slice(2..=4).unwrap().collect::<Vec<u8>>(), vec![2, 3, 4]);
+                eq!(chunked.slice(7..).unwrap().len(), 0);
+                assert!(chunked.slice(..=7).is_none());
+
+                let (start, end) = (5, 3);
+                assert!(chunked.slice(start..end).is_none());
+            }
+        }
+    }
+}
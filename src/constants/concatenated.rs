@@ -0,0 +1,251 @@
+use crate::{digits_of, IntegerDigits};
+use alloc::{vec, vec::Vec};
+use core::iter::FusedIterator;
+
+/// Infinite iterator over the decimal digits of the
+/// [Champernowne constant](https://en.wikipedia.org/wiki/Champernowne_constant)
+/// 0.123456789101112... - that is, over the concatenation of the positive
+/// integers, without the leading 0.
+///
+/// Since the position of each integer is known in advance, any digit can be
+/// reached directly - via [nth_digit](ChampernowneDigits::nth_digit),
+/// [starting_at](ChampernowneDigits::starting_at) or [Iterator::nth] -
+/// without enumerating the previous ones.
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::constants::ChampernowneDigits;
+///
+/// # fn main() -> GenericResult<()> {
+/// let head = ChampernowneDigits::new().take_sequence(15)?;
+/// assert_eq!(head.to_string(), "123456789101112");
+///
+/// assert_eq!(ChampernowneDigits::nth_digit(10), 0);
+/// assert_eq!(ChampernowneDigits::nth_digit(999_999_999_999), 1);
+///
+/// let far_away = ChampernowneDigits::starting_at(187).take_sequence(9)?;
+/// assert_eq!(far_away.to_string(), "991001011");
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChampernowneDigits {
+    current: IntegerDigits,
+    next_integer: u128,
+    next_index: u64,
+}
+
+impl ChampernowneDigits {
+    /// Creates an iterator starting from the first digit - that is, 1.
+    pub fn new() -> ChampernowneDigits {
+        ChampernowneDigits::starting_at(0)
+    }
+
+    /// Creates an iterator starting from the digit having the given 0-based index.
+    pub fn starting_at(index: u64) -> ChampernowneDigits {
+        let (integer, offset) = locate(index);
+
+        let mut current = digits_of(integer);
+        if offset > 0 {
+            current.nth(offset - 1);
+        }
+
+        ChampernowneDigits {
+            current,
+            next_integer: integer + 1,
+            next_index: index,
+        }
+    }
+
+    /// The digit having the given 0-based index, computed in O(log index) time.
+    pub fn nth_digit(index: u64) -> u8 {
+        let (integer, offset) = locate(index);
+
+        digits_of(integer)
+            .nth(offset)
+            .expect("The offset must be within the integer")
+    }
+}
+
+impl Default for ChampernowneDigits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The integer containing the digit at the given index, with the offset
+/// of the digit within the integer.
+fn locate(index: u64) -> (u128, usize) {
+    let mut remaining = index as u128;
+    let mut len = 1;
+    let mut first_integer = 1u128;
+
+    while remaining >= len * 9 * first_integer {
+        remaining -= len * 9 * first_integer;
+        len += 1;
+        first_integer *= 10;
+    }
+
+    (first_integer + remaining / len, (remaining % len) as usize)
+}
+
+impl Iterator for ChampernowneDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let digit = match self.current.next() {
+            Some(digit) => digit,
+            None => {
+                self.current = digits_of(self.next_integer);
+                self.next_integer += 1;
+
+                self.current.next()?
+            }
+        };
+
+        self.next_index += 1;
+
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<u8> {
+        *self = ChampernowneDigits::starting_at(self.next_index + n as u64);
+
+        self.next()
+    }
+}
+
+impl FusedIterator for ChampernowneDigits {}
+
+/// Infinite iterator over the decimal digits of the
+/// [Copeland–Erdős constant](https://en.wikipedia.org/wiki/Copeland%E2%80%93Erd%C5%91s_constant)
+/// 0.23571113171923... - that is, over the concatenation of the prime
+/// numbers, without the leading 0.
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::constants::CopelandErdosDigits;
+///
+/// # fn main() -> GenericResult<()> {
+/// let head = CopelandErdosDigits::new().take_sequence(15)?;
+/// assert_eq!(head.to_string(), "235711131719232");
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CopelandErdosDigits {
+    current: IntegerDigits,
+    primes: Vec<u64>,
+}
+
+impl CopelandErdosDigits {
+    /// Creates an iterator starting from the first digit - that is, 2.
+    pub fn new() -> CopelandErdosDigits {
+        CopelandErdosDigits {
+            current: digits_of(2u8),
+            primes: vec![2],
+        }
+    }
+
+    /// Finds the prime following the last one, via trial division
+    /// by the primes found so far.
+    fn next_prime(&mut self) -> u64 {
+        let last_prime = *self.primes.last().expect("There is always a prime");
+        let mut candidate = last_prime + 1 + (last_prime % 2);
+
+        while self
+            .primes
+            .iter()
+            .take_while(|&&prime| prime * prime <= candidate)
+            .any(|&prime| candidate.is_multiple_of(prime))
+        {
+            candidate += 2;
+        }
+
+        self.primes.push(candidate);
+
+        candidate
+    }
+}
+
+impl Default for CopelandErdosDigits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for CopelandErdosDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        match self.current.next() {
+            Some(digit) => Some(digit),
+            None => {
+                self.current = digits_of(self.next_prime());
+
+                self.current.next()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for CopelandErdosDigits {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitStream;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Digits of the Champernowne constant" {
+            it "should match the concatenation of the integers" {
+                let expected: String = (1..=1200).map(|integer| integer.to_string()).collect();
+                let actual = ChampernowneDigits::new().take_sequence(expected.len()).unwrap();
+
+                eq!(actual.to_string(), expected);
+            }
+
+            it "should compute any digit directly" {
+                let expected: Vec<u8> = ChampernowneDigits::new().take(3000).collect();
+
+                for (index, &digit) in expected.iter().enumerate() {
+                    eq!(ChampernowneDigits::nth_digit(index as u64), digit);
+                }
+            }
+
+            it "should skip ahead consistently" {
+                let mut digits = ChampernowneDigits::new();
+
+                eq!(digits.nth(8), Some(9));
+                eq!(digits.nth(1), Some(0));
+                eq!(digits.nth(2), Some(1));
+                eq!(digits.next(), Some(2));
+            }
+        }
+
+        describe "Digits of the Copeland–Erdős constant" {
+            it "should match the concatenation of the primes" {
+                let expected: String = (2u32..2000)
+                    .filter(|&candidate| (2..candidate).all(|factor| !candidate.is_multiple_of(factor)))
+                    .map(|prime| prime.to_string())
+                    .collect();
+
+                let actual = CopelandErdosDigits::new().take_sequence(expected.len()).unwrap();
+
+                eq!(actual.to_string(), expected);
+            }
+        }
+    }
+}
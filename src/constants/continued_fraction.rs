@@ -0,0 +1,286 @@
+use super::signed::SignedDigits;
+use super::small_quotient;
+use crate::arithmetic::{add, mul_small, sub_in_place};
+use crate::{digits_of, Digits, IntegerDigits};
+use core::iter::{Chain, FlatMap, FusedIterator, Once, Repeat};
+use core::ops::RangeFrom;
+
+/// Iterator over the decimal digits of the non-negative number described
+/// by the given [continued fraction](https://en.wikipedia.org/wiki/Continued_fraction)
+/// terms `[a0; a1, a2, ...]` - via Gosper's algorithm, requiring just
+/// the terms needed to ensure each digit.
+///
+/// The digits of the integer part `a0` come first; all the other terms
+/// must be positive. If the terms end, the fraction is rational, and the
+/// iterator keeps yielding its exact decimal expansion; without any term,
+/// no digit is yielded at all.
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::constants::ContinuedFractionDigits;
+///
+/// # fn main() -> GenericResult<()> {
+/// // The golden ratio is [1; 1, 1, 1, ...]
+/// let phi = ContinuedFractionDigits::new(std::iter::repeat(1)).take_sequence(12)?;
+/// assert_eq!(phi.to_string(), "161803398874");
+///
+/// // 355/113 is [3; 7, 16]
+/// let approximation = ContinuedFractionDigits::new([3, 7, 16]).take_sequence(10)?;
+/// assert_eq!(approximation.to_string(), "3141592920");
+///
+/// // The integer part can have any number of digits
+/// let quarter = ContinuedFractionDigits::new([12, 4]).take_sequence(6)?;
+/// assert_eq!(quarter.to_string(), "122500");
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContinuedFractionDigits<I> {
+    terms: I,
+    terms_ended: bool,
+    integer_part: Option<IntegerDigits>,
+    p: Digits,
+    q: SignedDigits,
+    r: Digits,
+    s: Digits,
+}
+
+impl<I: Iterator<Item = u64>> ContinuedFractionDigits<I> {
+    /// Creates an iterator over the digits of `[a0; a1, a2, ...]`.
+    pub fn new(terms: impl IntoIterator<IntoIter = I>) -> Self {
+        ContinuedFractionDigits {
+            terms: terms.into_iter(),
+            terms_ended: false,
+            integer_part: None,
+            p: Digits::from(&[1][..]),
+            q: SignedDigits::default(),
+            r: Digits::new(),
+            s: Digits::from(&[1][..]),
+        }
+    }
+
+    /// The next digit - or rather the integer part, at the beginning - if both
+    /// ends of the range still open to the remaining terms agree on it.
+    fn safe_digit(&self) -> Option<u64> {
+        if is_zero(&self.r) {
+            return None;
+        }
+
+        let lower_bound = small_quotient(&self.p, &self.r);
+        let upper_bound = small_quotient(
+            &self.q.add_natural(&self.p).into_natural(),
+            &add(&self.r, &self.s),
+        );
+
+        (lower_bound == upper_bound).then_some(lower_bound)
+    }
+
+    /// Subtracts the given digit from the value, then multiplies it by 10.
+    fn produce(&mut self, digit: u64) {
+        sub_in_place(&mut self.p, &mul_small(&self.r, digit));
+
+        self.p = mul_small(&self.p, 10);
+        self.q = self.q.sub_natural(&mul_small(&self.s, digit)).mul_small(10);
+    }
+
+    /// Absorbs the next term - or, if the terms have ended, collapses
+    /// the range onto its exact value.
+    fn consume(&mut self) {
+        match self.terms.next() {
+            Some(term) => {
+                let p = self.q.add_natural(&mul_small(&self.p, term)).into_natural();
+                let r = add(&mul_small(&self.r, term), &self.s);
+
+                self.q = SignedDigits::from_natural(core::mem::replace(&mut self.p, p));
+                self.s = core::mem::replace(&mut self.r, r);
+            }
+
+            None => {
+                self.terms_ended = true;
+                self.q = SignedDigits::from_natural(self.p.clone());
+                self.s = self.r.clone();
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = u64>> Iterator for ContinuedFractionDigits<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if let Some(digit) = self.integer_part.as_mut().and_then(Iterator::next) {
+            return Some(digit);
+        }
+
+        let digit = loop {
+            if let Some(digit) = self.safe_digit() {
+                break digit;
+            }
+
+            if self.terms_ended {
+                return None;
+            }
+
+            self.consume();
+        };
+
+        self.produce(digit);
+
+        if self.integer_part.is_some() {
+            return Some(digit as u8);
+        }
+
+        let mut integer_part = digits_of(digit);
+        let first_digit = integer_part.next();
+        self.integer_part = Some(integer_part);
+
+        first_digit
+    }
+}
+
+impl<I: Iterator<Item = u64>> FusedIterator for ContinuedFractionDigits<I> {}
+
+fn is_zero(digits: &[u8]) -> bool {
+    digits.iter().all(|&digit| digit == 0)
+}
+
+type ETerms = Chain<Once<u64>, FlatMap<RangeFrom<u64>, [u64; 3], fn(u64) -> [u64; 3]>>;
+
+/// Infinite iterator over the decimal digits of
+/// [e](https://en.wikipedia.org/wiki/E_(mathematical_constant)) - starting
+/// from the integer part, 2 - computed from its continued fraction
+/// `[2; 1, 2, 1, 1, 4, 1, 1, 6, ...]`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::constants::EDigits;
+///
+/// # fn main() -> GenericResult<()> {
+/// let e = EDigits::new().take_sequence(15)?;
+/// assert_eq!(e.to_string(), "271828182845904");
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EDigits(ContinuedFractionDigits<ETerms>);
+
+impl EDigits {
+    /// Creates an iterator starting from the first digit of e.
+    pub fn new() -> EDigits {
+        let periodic_terms: fn(u64) -> [u64; 3] = |index| [1, 2 * index, 1];
+
+        EDigits(ContinuedFractionDigits::new(
+            core::iter::once(2).chain((1..).flat_map(periodic_terms)),
+        ))
+    }
+}
+
+impl Default for EDigits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for EDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for EDigits {}
+
+/// Infinite iterator over the decimal digits of √2 - starting from the
+/// integer part, 1 - computed from its continued fraction `[1; 2, 2, 2, ...]`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::constants::Sqrt2Digits;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sqrt_2 = Sqrt2Digits::new().take_sequence(15)?;
+/// assert_eq!(sqrt_2.to_string(), "141421356237309");
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sqrt2Digits(ContinuedFractionDigits<Chain<Once<u64>, Repeat<u64>>>);
+
+impl Sqrt2Digits {
+    /// Creates an iterator starting from the first digit of √2.
+    pub fn new() -> Sqrt2Digits {
+        Sqrt2Digits(ContinuedFractionDigits::new(
+            core::iter::once(1).chain(core::iter::repeat(2)),
+        ))
+    }
+}
+
+impl Default for Sqrt2Digits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Sqrt2Digits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for Sqrt2Digits {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitStream;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    const E_100: &str = "2718281828459045235360287471352662497757247093699959574966967627724076630353547594571382178525166427";
+
+    const SQRT_2_100: &str = "1414213562373095048801688724209698078569671875376948073176679737990732478462107038850387534327641572";
+
+    speculate! {
+        describe "Digits of e" {
+            it "should match the first 100 digits" {
+                let e = EDigits::new().take_sequence(100).unwrap();
+
+                eq!(e.to_string(), E_100);
+            }
+        }
+
+        describe "Digits of √2" {
+            it "should match the first 100 digits" {
+                let sqrt_2 = Sqrt2Digits::new().take_sequence(100).unwrap();
+
+                eq!(sqrt_2.to_string(), SQRT_2_100);
+            }
+        }
+
+        describe "Digits of a continued fraction" {
+            it "should expand rational numbers exactly" {
+                let one_seventh = ContinuedFractionDigits::new([0, 7]).take_sequence(13).unwrap();
+
+                eq!(one_seventh.to_string(), "0142857142857");
+            }
+
+            it "should yield nothing without terms" {
+                eq!(ContinuedFractionDigits::new([]).next(), None);
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Digits of mathematical constants, generated on demand.
+//!
+//! The generators are unbounded iterators over 0-9 digits, so they can
+//! be collected into a [DigitSequence](crate::DigitSequence) or consumed
+//! as a [DigitStream](crate::DigitStream).
+
+mod concatenated;
+mod continued_fraction;
+mod pi;
+mod signed;
+
+pub use concatenated::*;
+pub use continued_fraction::*;
+pub use pi::*;
+
+use crate::arithmetic::div_rem;
+
+/// Quotient of a division known to fit into a [u64].
+fn small_quotient(dividend: &[u8], divisor: &[u8]) -> u64 {
+    div_rem(dividend, divisor)
+        .0
+        .iter()
+        .fold(0, |quotient, &digit| quotient * 10 + digit as u64)
+}
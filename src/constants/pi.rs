@@ -0,0 +1,146 @@
+use super::signed::SignedDigits;
+use super::small_quotient;
+use crate::arithmetic::mul_small;
+use crate::Digits;
+use core::iter::FusedIterator;
+
+/// Infinite iterator over the decimal digits of π - starting from the
+/// integer part, 3 - via
+/// [Gibbons' unbounded spigot algorithm](https://www.cs.ox.ac.uk/jeremy.gibbons/publications/spigot.pdf).
+///
+/// The state grows with the number of digits produced, so each digit is
+/// slightly more expensive than the previous one: thousands of digits
+/// are fine, but [MappedDigitFile](crate::MappedDigitFile) is a better fit
+/// for millions of precomputed digits.
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::constants::PiDigits;
+///
+/// # fn main() -> GenericResult<()> {
+/// let pi = PiDigits::new().take_sequence(15)?;
+/// assert_eq!(pi.to_string(), "314159265358979");
+///
+/// let pattern: DigitSequence = 2384u16.into();
+/// assert_eq!(PiDigits::new().find_pattern(&pattern), Some(16));
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PiDigits {
+    q: Digits,
+    r: SignedDigits,
+    t: Digits,
+    k: u64,
+    n: u64,
+    l: u64,
+}
+
+impl PiDigits {
+    /// Creates an iterator starting from the first digit of π.
+    pub fn new() -> PiDigits {
+        PiDigits {
+            q: Digits::from(&[1][..]),
+            r: SignedDigits::default(),
+            t: Digits::from(&[1][..]),
+            k: 1,
+            n: 3,
+            l: 3,
+        }
+    }
+
+    /// Tells whether the current estimate `n` is certainly the next digit -
+    /// that is, whether `4q + r < (n + 1)t`.
+    fn is_digit_safe(&self) -> bool {
+        self.r
+            .add_natural(&mul_small(&self.q, 4))
+            .is_less_than(&mul_small(&self.t, self.n + 1))
+    }
+
+    /// Removes the safe digit `n` from the state.
+    fn produce(&mut self) {
+        let three_q_plus_r = self.r.add_natural(&mul_small(&self.q, 3)).into_natural();
+        let next_n = small_quotient(&mul_small(&three_q_plus_r, 10), &self.t) - 10 * self.n;
+
+        self.r = self
+            .r
+            .sub_natural(&mul_small(&self.t, self.n))
+            .mul_small(10);
+        self.q = mul_small(&self.q, 10);
+        self.n = next_n;
+    }
+
+    /// Absorbs one more term of the series, refining the estimate `n`.
+    fn consume(&mut self) {
+        let estimate_numerator = self
+            .r
+            .mul_small(self.l)
+            .add_natural(&mul_small(&self.q, 7 * self.k + 2))
+            .into_natural();
+
+        self.r = self.r.add_natural(&mul_small(&self.q, 2)).mul_small(self.l);
+        self.q = mul_small(&self.q, self.k);
+        self.t = mul_small(&self.t, self.l);
+        self.n = small_quotient(&estimate_numerator, &self.t);
+        self.k += 1;
+        self.l += 2;
+    }
+}
+
+impl Default for PiDigits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for PiDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        while !self.is_digit_safe() {
+            self.consume();
+        }
+
+        let digit = self.n as u8;
+        self.produce();
+
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for PiDigits {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitStream;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    const PI_100: &str = "3141592653589793238462643383279502884197169399375105820974944592307816406286208998628034825342117067";
+
+    speculate! {
+        describe "Digits of π" {
+            it "should match the first 100 digits" {
+                let pi = PiDigits::new().take_sequence(100).unwrap();
+
+                eq!(pi.to_string(), PI_100);
+            }
+
+            it "should resume from a cloned state" {
+                let mut digits = PiDigits::new();
+                digits.nth(49);
+
+                let resumed = digits.clone().take_sequence(50).unwrap();
+
+                eq!(resumed.to_string(), PI_100[50..]);
+                eq!(digits.next(), Some(0));
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::arithmetic::{add, compare, mul_small, sub_in_place};
+use crate::Digits;
+use core::cmp::Ordering;
+
+/// Minimal signed integer - as the state of the spigot algorithms
+/// can temporarily become negative.
+#[derive(Debug, Clone, Default)]
+pub(super) struct SignedDigits {
+    negative: bool,
+    magnitude: Digits,
+}
+
+impl SignedDigits {
+    pub(super) fn from_natural(magnitude: Digits) -> SignedDigits {
+        SignedDigits {
+            negative: false,
+            magnitude,
+        }
+    }
+
+    /// Computes `minuend - subtrahend` for natural numbers.
+    fn difference(minuend: &[u8], subtrahend: &[u8]) -> SignedDigits {
+        let (mut magnitude, subtrahend, negative) = match compare(minuend, subtrahend) {
+            Ordering::Less => (Digits::from(subtrahend), minuend, true),
+            _ => (Digits::from(minuend), subtrahend, false),
+        };
+
+        sub_in_place(&mut magnitude, subtrahend);
+
+        SignedDigits {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub(super) fn add_natural(&self, natural: &[u8]) -> SignedDigits {
+        if self.negative {
+            SignedDigits::difference(natural, &self.magnitude)
+        } else {
+            SignedDigits {
+                negative: false,
+                magnitude: add(&self.magnitude, natural),
+            }
+        }
+    }
+
+    pub(super) fn sub_natural(&self, natural: &[u8]) -> SignedDigits {
+        if self.negative {
+            SignedDigits {
+                negative: true,
+                magnitude: add(&self.magnitude, natural),
+            }
+        } else {
+            SignedDigits::difference(&self.magnitude, natural)
+        }
+    }
+
+    pub(super) fn mul_small(&self, factor: u64) -> SignedDigits {
+        SignedDigits {
+            negative: self.negative,
+            magnitude: mul_small(&self.magnitude, factor),
+        }
+    }
+
+    pub(super) fn is_less_than(&self, natural: &[u8]) -> bool {
+        self.negative || compare(&self.magnitude, natural) == Ordering::Less
+    }
+
+    /// The magnitude of a value that, by the invariants of the spigot
+    /// algorithms, is never negative.
+    pub(super) fn into_natural(self) -> Digits {
+        debug_assert!(!self.negative, "The value must not be negative");
+
+        self.magnitude
+    }
+}
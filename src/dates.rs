@@ -0,0 +1,220 @@
+use crate::result::require_len;
+use crate::{CrateError, CrateResult, DigitSequence, Digits};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+const DATE_LEN: usize = 8;
+const DATE_TIME_LEN: usize = 14;
+
+impl DigitSequence {
+    /// Converts a date to its `yyyymmdd` digits - as long as the year
+    /// is within 0..=9999: earlier years result in a [CrateError::NegativeNumber],
+    /// later ones in a [CrateError::Overflow].
+    ///
+    /// **REQUIRES FEATURE**: `chrono`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use chrono::NaiveDate;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 9).unwrap();
+    /// assert_eq!(DigitSequence::from_date(date)?.to_string(), "20240209");
+    ///
+    /// let date = NaiveDate::from_ymd_opt(10000, 1, 1).unwrap();
+    /// assert_eq!(DigitSequence::from_date(date), Err(CrateError::Overflow));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_date(date: NaiveDate) -> CrateResult<DigitSequence> {
+        let mut digits = Digits::with_capacity(DATE_LEN);
+
+        push_date(&mut digits, date)?;
+
+        Ok(DigitSequence(digits))
+    }
+
+    /// Converts a date and time to its `yyyymmddHHMMSS` digits - dropping
+    /// the fraction of second; the year must be within 0..=9999,
+    /// just like in [from_date](DigitSequence::from_date).
+    ///
+    /// **REQUIRES FEATURE**: `chrono`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use chrono::NaiveDate;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let date_time = NaiveDate::from_ymd_opt(1999, 12, 31)
+    ///     .unwrap()
+    ///     .and_hms_milli_opt(23, 5, 7, 890)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(DigitSequence::from_date_time(date_time)?.to_string(), "19991231230507");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_date_time(date_time: NaiveDateTime) -> CrateResult<DigitSequence> {
+        let mut digits = Digits::with_capacity(DATE_TIME_LEN);
+
+        push_date(&mut digits, date_time.date())?;
+        push_field(&mut digits, date_time.hour(), 2);
+        push_field(&mut digits, date_time.minute(), 2);
+        push_field(&mut digits, date_time.second(), 2);
+
+        Ok(DigitSequence(digits))
+    }
+
+    /// Parses `yyyymmdd` digits back to a date: a sequence having
+    /// a different length results in a [CrateError::LengthMismatch],
+    /// a nonexistent date in a [CrateError::InvalidDateTime].
+    ///
+    /// **REQUIRES FEATURE**: `chrono`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use chrono::NaiveDate;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = "20240229".parse()?;
+    /// assert_eq!(sequence.to_date()?, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    ///
+    /// let sequence: DigitSequence = "20230229".parse()?;
+    /// assert_eq!(sequence.to_date(), Err(CrateError::InvalidDateTime));
+    ///
+    /// let sequence: DigitSequence = "240229".parse()?;
+    /// assert_eq!(
+    ///     sequence.to_date(),
+    ///     Err(CrateError::LengthMismatch { expected: 8, actual: 6 })
+    /// );
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_date(&self) -> CrateResult<NaiveDate> {
+        require_len(self.0.len(), DATE_LEN..=DATE_LEN)?;
+
+        read_date(&self.0)
+    }
+
+    /// Parses `yyyymmddHHMMSS` digits back to a date and time: a sequence having
+    /// a different length results in a [CrateError::LengthMismatch],
+    /// a nonexistent date or time in a [CrateError::InvalidDateTime].
+    ///
+    /// **REQUIRES FEATURE**: `chrono`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use chrono::NaiveDate;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = "19991231230507".parse()?;
+    ///
+    /// assert_eq!(
+    ///     sequence.to_date_time()?,
+    ///     NaiveDate::from_ymd_opt(1999, 12, 31).unwrap().and_hms_opt(23, 5, 7).unwrap()
+    /// );
+    ///
+    /// let sequence: DigitSequence = "19991231240000".parse()?;
+    /// assert_eq!(sequence.to_date_time(), Err(CrateError::InvalidDateTime));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_date_time(&self) -> CrateResult<NaiveDateTime> {
+        require_len(self.0.len(), DATE_TIME_LEN..=DATE_TIME_LEN)?;
+
+        let date = read_date(&self.0[..DATE_LEN])?;
+
+        let time = NaiveTime::from_hms_opt(
+            read_field(&self.0[8..10]),
+            read_field(&self.0[10..12]),
+            read_field(&self.0[12..14]),
+        )
+        .ok_or(CrateError::InvalidDateTime)?;
+
+        Ok(date.and_time(time))
+    }
+}
+
+fn push_date(digits: &mut Digits, date: NaiveDate) -> CrateResult<()> {
+    let year = date.year();
+
+    if year < 0 {
+        return Err(CrateError::NegativeNumber(year as i128));
+    }
+
+    if year > 9999 {
+        return Err(CrateError::Overflow);
+    }
+
+    push_field(digits, year as u32, 4);
+    push_field(digits, date.month(), 2);
+    push_field(digits, date.day(), 2);
+
+    Ok(())
+}
+
+/// Appends the digits of the value, left-padded with zeros to the given width.
+fn push_field(digits: &mut Digits, value: u32, width: u32) {
+    for exponent in (0..width).rev() {
+        digits.push((value / 10u32.pow(exponent) % 10) as u8);
+    }
+}
+
+fn read_date(digits: &[u8]) -> CrateResult<NaiveDate> {
+    NaiveDate::from_ymd_opt(
+        read_field(&digits[..4]) as i32,
+        read_field(&digits[4..6]),
+        read_field(&digits[6..8]),
+    )
+    .ok_or(CrateError::InvalidDateTime)
+}
+
+fn read_field(digits: &[u8]) -> u32 {
+    digits
+        .iter()
+        .fold(0, |value, &digit| value * 10 + digit as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Converting dates" {
+            it "should round-trip the boundary years" {
+                for date in [NaiveDate::from_ymd_opt(0, 1, 1).unwrap(), NaiveDate::from_ymd_opt(9999, 12, 31).unwrap()] {
+                    eq!(DigitSequence::from_date(date).unwrap().to_date(), Ok(date));
+                }
+            }
+
+            it "should reject negative years" {
+                let date = NaiveDate::from_ymd_opt(-1, 1, 1).unwrap();
+
+                eq!(DigitSequence::from_date(date), Err(CrateError::NegativeNumber(-1)));
+            }
+        }
+
+        describe "Converting dates and times" {
+            it "should round-trip every second of a day" {
+                let midnight = NaiveDate::from_ymd_opt(2000, 2, 29).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+                for seconds in (0..86_400).step_by(37) {
+                    let date_time = midnight + chrono::Duration::seconds(seconds);
+
+                    eq!(DigitSequence::from_date_time(date_time).unwrap().to_date_time(), Ok(date_time));
+                }
+            }
+
+            it "should reject a nonexistent date" {
+                let sequence: DigitSequence = "20001301120000".parse().unwrap();
+
+                eq!(sequence.to_date_time(), Err(CrateError::InvalidDateTime));
+            }
+        }
+    }
+}
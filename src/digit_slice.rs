@@ -0,0 +1,370 @@
+use crate::strings::ASCII_WRITE_BUFFER_SIZE;
+use crate::{CrateError, CrateResult, DigitIter, DigitSequence};
+use core::fmt::{Alignment, Display, Write};
+use core::iter;
+
+/// Borrowed, read-only view over validated 0-9 digits - to [DigitSequence]
+/// what &[str] is to [String](alloc::string::String).
+///
+/// Functions that only need to read digits can take a [DigitSlice], which is
+/// [Copy] and can be obtained from a [DigitSequence] without cloning.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// fn describe(digits: DigitSlice) -> String {
+///     format!("{} ({} digits)", digits, digits.len())
+/// }
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: DigitSequence = "4111111111111111".parse()?;
+/// let slice = sequence.as_digit_slice();
+///
+/// assert_eq!(describe(slice), "4111111111111111 (16 digits)");
+/// assert!(slice.luhn_is_valid());
+/// assert_eq!(slice, sequence);
+///
+/// let payload = slice.get(..15).unwrap();
+/// assert_eq!(payload.luhn_check_digit(), 1);
+///
+/// let owned: DigitSequence = payload.into();
+/// assert_eq!(owned.to_string(), "411111111111111");
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// A slice of numbers can be viewed directly, as long as it only contains
+/// 0-9 digits - otherwise, the result is a [CrateError::NonDigitNumber]:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let source: &[u8] = &[9, 0, 2];
+/// let slice: DigitSlice = source.try_into()?;
+/// assert_eq!(slice, [9, 0, 2]);
+///
+/// let source: &[u8] = &[9, 10];
+/// let result: CrateResult<DigitSlice> = source.try_into();
+/// assert_eq!(result, Err(CrateError::NonDigitNumber(10)));
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DigitSlice<'a>(pub(crate) &'a [u8]);
+
+/// Tells whether all the given numbers are 0-9 digits - also in const contexts,
+/// for example to check the invariants of static tables.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// const VALID: bool = validate_digits(&[3, 9, 0]);
+/// assert!(VALID);
+///
+/// assert!(validate_digits(&[]));
+/// assert!(!validate_digits(&[3, 10]));
+/// ```
+pub const fn validate_digits(digits: &[u8]) -> bool {
+    let mut index = 0;
+
+    while index < digits.len() {
+        if digits[index] >= 10 {
+            return false;
+        }
+
+        index += 1;
+    }
+
+    true
+}
+
+impl<'a> DigitSlice<'a> {
+    /// Views the given numbers as digits - returning [None] if any of them
+    /// is not a 0-9 digit; unlike [TryFrom], it is available in const contexts.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// const ITALY: DigitSlice = DigitSlice::new(&[3, 9]).unwrap();
+    /// assert_eq!(ITALY.to_string(), "39");
+    ///
+    /// assert_eq!(DigitSlice::new(&[3, 90]), None);
+    /// ```
+    pub const fn new(digits: &'a [u8]) -> Option<DigitSlice<'a>> {
+        if validate_digits(digits) {
+            Some(DigitSlice(digits))
+        } else {
+            None
+        }
+    }
+
+    /// Number of digits in the slice.
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Tells whether the slice is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The underlying digits.
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Iterates over the digits.
+    pub fn iter(&self) -> DigitIter<'a> {
+        DigitIter(self.0.iter())
+    }
+
+    /// The sub-slice within the given range, if the range is in bounds.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 90781u32.into();
+    /// let slice = sequence.as_digit_slice();
+    ///
+    /// assert_eq!(slice.get(1..3).unwrap(), [0, 7]);
+    /// assert_eq!(slice.get(3..).unwrap(), [8, 1]);
+    /// assert_eq!(slice.get(4..9), None);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get<R>(&self, range: R) -> Option<DigitSlice<'a>>
+    where
+        R: core::slice::SliceIndex<[u8], Output = [u8]>,
+    {
+        self.0.get(range).map(DigitSlice)
+    }
+}
+
+impl DigitSequence {
+    /// Borrows the digits as a [DigitSlice] - without cloning them.
+    pub fn as_digit_slice(&self) -> DigitSlice<'_> {
+        DigitSlice(&self.0)
+    }
+}
+
+impl<'a> IntoIterator for DigitSlice<'a> {
+    type Item = &'a u8;
+
+    type IntoIter = DigitIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for DigitSlice<'a> {
+    type Error = CrateError;
+
+    fn try_from(digits: &'a [u8]) -> CrateResult<Self> {
+        match digits.iter().find(|&&digit| digit >= 10) {
+            Some(&digit) => Err(CrateError::NonDigitNumber(digit as u128)),
+            None => Ok(DigitSlice(digits)),
+        }
+    }
+}
+
+impl<'a> From<&'a DigitSequence> for DigitSlice<'a> {
+    fn from(sequence: &'a DigitSequence) -> DigitSlice<'a> {
+        sequence.as_digit_slice()
+    }
+}
+
+/// Conversion to an owned [DigitSequence] copies the digits.
+impl From<DigitSlice<'_>> for DigitSequence {
+    fn from(slice: DigitSlice<'_>) -> DigitSequence {
+        DigitSequence(slice.0.into())
+    }
+}
+
+/// The string representation is the same as [DigitSequence] - including
+/// the standard formatting flags and the alternate form.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let sequence: DigitSequence = 1234567u32.into();
+/// let slice = sequence.as_digit_slice();
+///
+/// assert_eq!(slice.to_string(), "1234567");
+/// assert_eq!(format!("{:>9}", slice), "  1234567");
+/// assert_eq!(format!("{:09}", slice), "001234567");
+/// assert_eq!(format!("{:#}", slice), "1 234 567");
+/// ```
+impl Display for DigitSlice<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !f.alternate() && f.width().is_none() && f.precision().is_none() {
+            let mut buffer = [0u8; ASCII_WRITE_BUFFER_SIZE];
+
+            for chunk in self.0.chunks(ASCII_WRITE_BUFFER_SIZE) {
+                for (target, &digit) in buffer.iter_mut().zip(chunk) {
+                    *target = b'0' + digit;
+                }
+
+                let ascii_digits =
+                    core::str::from_utf8(&buffer[..chunk.len()]).expect("Digits are always ASCII");
+
+                f.write_str(ascii_digits)?;
+            }
+
+            return Ok(());
+        }
+
+        let alternate = f.alternate();
+        let separators = if alternate {
+            self.0.len().saturating_sub(1) / 3
+        } else {
+            0
+        };
+        let rendered_len = self.0.len() + separators;
+
+        let zero_pad = f.sign_aware_zero_pad();
+        let shown_len = if zero_pad {
+            rendered_len
+        } else {
+            f.precision()
+                .map_or(rendered_len, |precision| precision.min(rendered_len))
+        };
+        let padding = f.width().map_or(0, |width| width.saturating_sub(shown_len));
+
+        let (fill, leading, trailing) = if zero_pad {
+            ('0', padding, 0)
+        } else {
+            match f.align() {
+                Some(Alignment::Right) => (f.fill(), padding, 0),
+                Some(Alignment::Center) => (f.fill(), padding / 2, padding.div_ceil(2)),
+                _ => (f.fill(), 0, padding),
+            }
+        };
+
+        let chars = self.0.iter().enumerate().flat_map(|(index, &digit)| {
+            let separator = alternate && index > 0 && (self.0.len() - index).is_multiple_of(3);
+
+            separator
+                .then_some(' ')
+                .into_iter()
+                .chain(iter::once((b'0' + digit) as char))
+        });
+
+        for _ in 0..leading {
+            f.write_char(fill)?;
+        }
+
+        for current_char in chars.take(shown_len) {
+            f.write_char(current_char)?;
+        }
+
+        for _ in 0..trailing {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// [DigitSlice] can be compared with an array of [u8].
+impl<const N: usize> PartialEq<[u8; N]> for DigitSlice<'_> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.0 == other
+    }
+}
+
+/// [DigitSlice] can be compared with a slice of [u8].
+impl PartialEq<&[u8]> for DigitSlice<'_> {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.0 == *other
+    }
+}
+
+/// [DigitSlice] can be compared with a [DigitSequence].
+impl PartialEq<DigitSequence> for DigitSlice<'_> {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        self.0 == &other.0[..]
+    }
+}
+
+/// [DigitSequence] can be compared with a [DigitSlice].
+impl PartialEq<DigitSlice<'_>> for DigitSequence {
+    fn eq(&self, other: &DigitSlice<'_>) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Digit slice" {
+            it "should display just like the sequence" {
+                for value in [0u64, 7, 12, 123, 1234, 9876543210] {
+                    let sequence = DigitSequence::from(value);
+                    let slice = sequence.as_digit_slice();
+
+                    eq!(slice.to_string(), sequence.to_string());
+                    eq!(format!("{:#012}", slice), format!("{:#012}", sequence));
+                }
+            }
+
+            it "should display sequences longer than the buffer" {
+                let source = "9081726354".repeat(60);
+                let sequence: DigitSequence = source.parse().unwrap();
+
+                eq!(sequence.as_digit_slice().to_string(), source);
+                eq!(format!("{:.5}", sequence), "90817");
+            }
+
+            it "should pad and truncate just like a string" {
+                let sequence: DigitSequence = 1234567u32.into();
+                let slice = sequence.as_digit_slice();
+
+                eq!(format!("{:*^13}", slice), format!("{:*^13}", "1234567"));
+                eq!(format!("{:>10.3}", slice), format!("{:>10.3}", "1234567"));
+                eq!(format!("{:<#12}", slice), format!("{:<12}", "1 234 567"));
+                eq!(format!("{:#012}", slice), "0001 234 567");
+            }
+
+            it "should check just like the sequence" {
+                for source in ["9780306406157", "0306406152", "3214282912345698765432161182", "1729", "153", "9376", "7777"] {
+                    let sequence: DigitSequence = source.parse().unwrap();
+                    let slice = sequence.as_digit_slice();
+
+                    eq!(slice.gtin_is_valid(), sequence.gtin_is_valid());
+                    eq!(slice.isbn10_is_valid(), sequence.isbn10_is_valid());
+                    eq!(slice.isbn13_is_valid(), sequence.isbn13_is_valid());
+                    eq!(slice.mod97(), sequence.mod97());
+                    eq!(slice.is_repdigit(), sequence.is_repdigit());
+                    eq!(slice.is_divisible_by(7), sequence.is_divisible_by(7));
+                    eq!(slice.is_harshad(), sequence.is_harshad());
+                    eq!(slice.is_armstrong(), sequence.is_armstrong());
+                    eq!(slice.is_automorphic(), sequence.is_automorphic());
+                }
+            }
+
+            it "should order just like the sequence" {
+                let short: DigitSequence = [9].try_into().unwrap();
+                let long: DigitSequence = [1, 0].try_into().unwrap();
+
+                eq!(short.as_digit_slice().cmp(&long.as_digit_slice()), short.cmp(&long));
+            }
+
+            it "should be empty for the empty sequence" {
+                let sequence = DigitSequence::new();
+
+                assert!(sequence.as_digit_slice().is_empty());
+                eq!(sequence.as_digit_slice(), DigitSlice::default());
+            }
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use crate::integers::digits_value;
+use crate::{validate_digits, CrateError, CrateResult, DigitIter, DigitSequence, DigitSlice};
+use core::fmt::Display;
+use core::str::FromStr;
+
+/// Sequence of *exactly* `N` digits, stored in a `[u8; N]` - so that it lives
+/// on the stack and is [Copy]: ideal for fixed-width codes such as PINs,
+/// one-time passwords and ZIP codes.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let pin: FixedDigitSequence<4> = "0472".parse()?;
+/// let copy = pin;
+///
+/// assert_eq!(pin, [0, 4, 7, 2]);
+/// assert_eq!(copy.to_string(), "0472");
+///
+/// let value: u16 = pin.try_into()?;
+/// assert_eq!(value, 472);
+///
+/// let sequence: DigitSequence = pin.into();
+/// let roundtrip: FixedDigitSequence<4> = sequence.try_into()?;
+/// assert_eq!(roundtrip, pin);
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Any other length results in a [CrateError::LengthMismatch]:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let result: CrateResult<FixedDigitSequence<5>> = "0472".parse();
+/// assert_eq!(result, Err(CrateError::LengthMismatch { expected: 5, actual: 4 }));
+///
+/// let sequence: DigitSequence = 907u16.into();
+/// let result: CrateResult<FixedDigitSequence<2>> = sequence.try_into();
+/// assert_eq!(result, Err(CrateError::LengthMismatch { expected: 2, actual: 3 }));
+/// ```
+///
+/// Since all the instances have the same length, ordering is also numeric:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let smaller: FixedDigitSequence<3> = "099".parse()?;
+/// let bigger: FixedDigitSequence<3> = "100".parse()?;
+///
+/// assert!(smaller < bigger);
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedDigitSequence<const N: usize>([u8; N]);
+
+impl<const N: usize> FixedDigitSequence<N> {
+    /// Creates a sequence from the given array - returning [None] if any
+    /// of its values is not a 0-9 digit; unlike [TryFrom], it is available
+    /// in const contexts.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// const DEFAULT_PIN: FixedDigitSequence<4> = FixedDigitSequence::new([1, 2, 3, 4]).unwrap();
+    /// assert_eq!(DEFAULT_PIN.to_string(), "1234");
+    ///
+    /// assert_eq!(FixedDigitSequence::new([1, 20]), None);
+    /// ```
+    pub const fn new(digits: [u8; N]) -> Option<Self> {
+        if validate_digits(&digits) {
+            Some(FixedDigitSequence(digits))
+        } else {
+            None
+        }
+    }
+
+    /// The digits, as an array.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let zip_code: FixedDigitSequence<5> = "02134".parse()?;
+    ///
+    /// assert_eq!(zip_code.as_array(), &[0, 2, 1, 3, 4]);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn as_array(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Borrowed view over the digits, exposing the read-only operations
+    /// of [DigitSlice].
+    pub fn as_digit_slice(&self) -> DigitSlice<'_> {
+        DigitSlice(&self.0)
+    }
+
+    /// Iterates over the digits.
+    pub fn iter(&self) -> DigitIter<'_> {
+        DigitIter(self.0.iter())
+    }
+}
+
+/// The default value consists of `N` zeros.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// assert_eq!(FixedDigitSequence::<3>::default(), [0, 0, 0]);
+/// ```
+impl<const N: usize> Default for FixedDigitSequence<N> {
+    fn default() -> Self {
+        FixedDigitSequence([0; N])
+    }
+}
+
+/// An array of `N` numbers can be converted as long as
+/// its values are 0-9 digits - otherwise, the result is a
+/// [CrateError::NonDigitNumber].
+impl<const N: usize> TryFrom<[u8; N]> for FixedDigitSequence<N> {
+    type Error = CrateError;
+
+    fn try_from(digits: [u8; N]) -> CrateResult<Self> {
+        match digits.iter().find(|&&digit| digit >= 10) {
+            Some(&digit) => Err(CrateError::NonDigitNumber(digit as u128)),
+            None => Ok(FixedDigitSequence(digits)),
+        }
+    }
+}
+
+/// A slice must have exactly `N` values, all being 0-9 digits.
+impl<const N: usize> TryFrom<&[u8]> for FixedDigitSequence<N> {
+    type Error = CrateError;
+
+    fn try_from(digits: &[u8]) -> CrateResult<Self> {
+        let array: [u8; N] = digits.try_into().map_err(|_| CrateError::LengthMismatch {
+            expected: N,
+            actual: digits.len(),
+        })?;
+
+        array.try_into()
+    }
+}
+
+/// Conversion from a &[DigitSequence] only requires the length to be exactly `N`.
+impl<const N: usize> TryFrom<&DigitSequence> for FixedDigitSequence<N> {
+    type Error = CrateError;
+
+    fn try_from(sequence: &DigitSequence) -> CrateResult<Self> {
+        sequence.0.as_slice().try_into()
+    }
+}
+
+/// Conversion from a [DigitSequence] only requires the length to be exactly `N`.
+impl<const N: usize> TryFrom<DigitSequence> for FixedDigitSequence<N> {
+    type Error = CrateError;
+
+    fn try_from(sequence: DigitSequence) -> CrateResult<Self> {
+        (&sequence).try_into()
+    }
+}
+
+/// Conversion to [DigitSequence] is always infallible.
+impl<const N: usize> From<FixedDigitSequence<N>> for DigitSequence {
+    fn from(sequence: FixedDigitSequence<N>) -> DigitSequence {
+        DigitSequence(sequence.0[..].into())
+    }
+}
+
+/// Parsing requires exactly `N` ASCII digits.
+impl<const N: usize> FromStr for FixedDigitSequence<N> {
+    type Err = CrateError;
+
+    fn from_str(s: &str) -> CrateResult<Self> {
+        let mut digits = [0; N];
+        let mut len = 0;
+
+        for current_char in s.chars() {
+            let digit = match current_char {
+                '0'..='9' => current_char as u8 - b'0',
+                _ => return Err(CrateError::NonDigitChar(current_char)),
+            };
+
+            if let Some(target) = digits.get_mut(len) {
+                *target = digit;
+            }
+
+            len += 1;
+        }
+
+        if len != N {
+            return Err(CrateError::LengthMismatch {
+                expected: N,
+                actual: len,
+            });
+        }
+
+        Ok(FixedDigitSequence(digits))
+    }
+}
+
+/// The string representation is the same as [DigitSequence] - including
+/// the standard formatting flags and the alternate form - without allocating.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let code: FixedDigitSequence<6> = "004217".parse()?;
+///
+/// assert_eq!(format!("{:>8}", code), "  004217");
+/// assert_eq!(format!("{:#}", code), "004 217");
+///
+/// # Ok(())
+/// # }
+/// ```
+impl<const N: usize> Display for FixedDigitSequence<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_digit_slice().fmt(f)
+    }
+}
+
+/// [FixedDigitSequence] can be compared with an array of [u8].
+impl<const N: usize> PartialEq<[u8; N]> for FixedDigitSequence<N> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+/// [FixedDigitSequence] can be compared with a [DigitSequence].
+impl<const N: usize> PartialEq<DigitSequence> for FixedDigitSequence<N> {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        self.0 == *other.0
+    }
+}
+
+/// [DigitSequence] can be compared with a [FixedDigitSequence].
+impl<const N: usize> PartialEq<FixedDigitSequence<N>> for DigitSequence {
+    fn eq(&self, other: &FixedDigitSequence<N>) -> bool {
+        *self.0 == other.0
+    }
+}
+
+macro_rules! impl_try_to_unsigned {
+    ($type: ty) => {
+        /// Conversion to an *unsigned* integer might result in a [CrateError::Overflow];
+        /// leading zeros, however, never cause an overflow.
+        impl<const N: usize> TryFrom<FixedDigitSequence<N>> for $type {
+            type Error = CrateError;
+
+            fn try_from(sequence: FixedDigitSequence<N>) -> CrateResult<Self> {
+                digits_value(&sequence.0)
+            }
+        }
+    };
+}
+
+impl_try_to_unsigned!(u128);
+impl_try_to_unsigned!(u64);
+impl_try_to_unsigned!(u32);
+impl_try_to_unsigned!(u16);
+impl_try_to_unsigned!(u8);
+impl_try_to_unsigned!(usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Parsing a fixed sequence" {
+            it "should report a longer string" {
+                let result: CrateResult<FixedDigitSequence<2>> = "1234".parse();
+
+                eq!(result, Err(CrateError::LengthMismatch { expected: 2, actual: 4 }));
+            }
+
+            it "should report a non-digit char before the length" {
+                let result: CrateResult<FixedDigitSequence<2>> = "12x4".parse();
+
+                eq!(result, Err(CrateError::NonDigitChar('x')));
+            }
+        }
+
+        describe "Converting a fixed sequence to integers" {
+            it "should ignore leading zeros" {
+                let sequence: FixedDigitSequence<45> = "000000000000000000000000000000000000000000255".parse().unwrap();
+                let value: u8 = sequence.try_into().unwrap();
+
+                eq!(value, 255);
+            }
+
+            it "should report an overflow" {
+                let sequence: FixedDigitSequence<3> = "256".parse().unwrap();
+                let result: CrateResult<u8> = sequence.try_into();
+
+                eq!(result, Err(CrateError::Overflow));
+            }
+        }
+
+        describe "Formatting a fixed sequence" {
+            it "should support alignment" {
+                let sequence: FixedDigitSequence<2> = "07".parse().unwrap();
+
+                eq!(format!("{:*<4}", sequence), "07**");
+            }
+
+            it "should support the alternate form" {
+                let sequence: FixedDigitSequence<7> = "0012345".parse().unwrap();
+
+                eq!(format!("{:#}", sequence), "0 012 345");
+            }
+        }
+
+        describe "Comparing a fixed sequence" {
+            it "should equal the digit sequence with the same digits" {
+                let fixed: FixedDigitSequence<3> = "042".parse().unwrap();
+                let sequence: DigitSequence = "042".parse().unwrap();
+
+                eq!(sequence, fixed);
+                eq!(fixed, sequence);
+            }
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{CrateError, CrateResult, DigitSequence, DigitSlice};
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Write};
+
+/// Writes the given characters, inserting the separator between groups of the given size.
+///
+/// Groups are counted from the left - unless `from_right` is set,
+/// in which case the leftmost group might be shorter.
+fn write_grouped(
+    f: &mut Formatter<'_>,
+    chars: impl ExactSizeIterator<Item = char>,
+    group_size: usize,
+    separator: char,
+    from_right: bool,
+) -> core::fmt::Result {
+    let len = chars.len();
+
+    for (index, current_char) in chars.enumerate() {
+        let is_group_start = if from_right {
+            (len - index).is_multiple_of(group_size)
+        } else {
+            index.is_multiple_of(group_size)
+        };
+
+        if index > 0 && is_group_start {
+            f.write_char(separator)?;
+        }
+
+        f.write_char(current_char)?;
+    }
+
+    Ok(())
+}
+
+fn digit_char(digit: u8) -> char {
+    (b'0' + digit) as char
+}
+
+/// Masked representation of a [DigitSequence], returned by
+/// [DigitSequence::masked_display].
+#[derive(Debug, Clone, Copy)]
+pub struct MaskedDisplay<'a> {
+    sequence: &'a DigitSequence,
+    keep_last: usize,
+    mask_char: char,
+    grouping: Option<(usize, char)>,
+}
+
+impl MaskedDisplay<'_> {
+    /// Inserts the given separator between groups of the given size,
+    /// counted from the left.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let card_number: DigitSequence = "4111111111111234".parse()?;
+    ///
+    /// assert_eq!(
+    ///     card_number.masked_display(4, '*').with_grouping(4, ' ').to_string(),
+    ///     "**** **** **** 1234"
+    /// );
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `group_size` is 0.
+    pub fn with_grouping(self, group_size: usize, separator: char) -> Self {
+        assert!(group_size > 0, "The group size must be positive");
+
+        MaskedDisplay {
+            grouping: Some((group_size, separator)),
+            ..self
+        }
+    }
+}
+
+impl Display for MaskedDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let digits = &self.sequence.0;
+        let masked_len = digits.len().saturating_sub(self.keep_last);
+
+        let mut chars = digits.iter().enumerate().map(|(index, &digit)| {
+            if index < masked_len {
+                self.mask_char
+            } else {
+                digit_char(digit)
+            }
+        });
+
+        match self.grouping {
+            Some((group_size, separator)) => write_grouped(f, chars, group_size, separator, false),
+            None => chars.try_for_each(|current_char| f.write_char(current_char)),
+        }
+    }
+}
+
+/// Representation of a [DigitSequence] with separators between groups of digits,
+/// returned by [DigitSequence::grouped] and [DigitSlice::grouped].
+#[derive(Debug, Clone, Copy)]
+pub struct Grouped<'a> {
+    digits: &'a [u8],
+    group_size: usize,
+    separator: char,
+}
+
+impl Display for Grouped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let chars = self.digits.iter().map(|&digit| digit_char(digit));
+
+        write_grouped(f, chars, self.group_size, self.separator, true)
+    }
+}
+
+impl<'a> DigitSlice<'a> {
+    /// Just like [DigitSequence::grouped].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 1234567u32.into();
+    /// assert_eq!(sequence.as_digit_slice().grouped(3, ',').to_string(), "1,234,567");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `group_size` is 0.
+    pub fn grouped(&self, group_size: usize, separator: char) -> Grouped<'a> {
+        assert!(group_size > 0, "The group size must be positive");
+
+        Grouped {
+            digits: self.0,
+            group_size,
+            separator,
+        }
+    }
+}
+
+impl DigitSequence {
+    /// Displays the sequence with the given separator between groups of the given size,
+    /// counted from the right - like thousands separators.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 1234567u32.into();
+    /// assert_eq!(sequence.grouped(3, ',').to_string(), "1,234,567");
+    ///
+    /// let sequence: DigitSequence = 123456u32.into();
+    /// assert_eq!(sequence.grouped(3, '_').to_string(), "123_456");
+    ///
+    /// let sequence: DigitSequence = 12u8.into();
+    /// assert_eq!(sequence.grouped(3, ',').to_string(), "12");
+    ///
+    /// assert_eq!(DigitSequence::new().grouped(3, ',').to_string(), "");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `group_size` is 0.
+    pub fn grouped(&self, group_size: usize, separator: char) -> Grouped<'_> {
+        self.as_digit_slice().grouped(group_size, separator)
+    }
+
+    /// Displays the sequence with every digit replaced by `mask_char`,
+    /// except the last `keep_last` digits - for compliance-friendly output;
+    /// grouping can be added via [MaskedDisplay::with_grouping].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let card_number: DigitSequence = "4111111111111234".parse()?;
+    /// assert_eq!(card_number.masked_display(4, '*').to_string(), "************1234");
+    ///
+    /// let pin: DigitSequence = "0392".parse()?;
+    /// assert_eq!(pin.masked_display(0, '•').to_string(), "••••");
+    /// assert_eq!(pin.masked_display(10, '*').to_string(), "0392");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn masked_display(&self, keep_last: usize, mask_char: char) -> MaskedDisplay<'_> {
+        MaskedDisplay {
+            sequence: self,
+            keep_last,
+            mask_char,
+            grouping: None,
+        }
+    }
+
+    /// Formats the sequence according to the given pattern, where each `#`
+    /// is replaced by the next digit and any other character is emitted literally.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let card_number: DigitSequence = "4111111111111111".parse()?;
+    /// assert_eq!(card_number.format_pattern("#### #### #### ####")?, "4111 1111 1111 1111");
+    ///
+    /// let phone_number: DigitSequence = "5551234567".parse()?;
+    /// assert_eq!(phone_number.format_pattern("(###) ###-####")?, "(555) 123-4567");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The number of `#` placeholders must match the length of the sequence -
+    /// otherwise, the result is a [CrateError::LengthMismatch]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let ssn: DigitSequence = "12345678".parse()?;
+    ///
+    /// assert_eq!(
+    ///     ssn.format_pattern("###-##-####"),
+    ///     Err(CrateError::LengthMismatch { expected: 9, actual: 8 })
+    /// );
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn format_pattern(&self, pattern: &str) -> CrateResult<String> {
+        let placeholders = pattern
+            .chars()
+            .filter(|&current_char| current_char == '#')
+            .count();
+
+        if placeholders != self.0.len() {
+            return Err(CrateError::LengthMismatch {
+                expected: placeholders,
+                actual: self.0.len(),
+            });
+        }
+
+        let mut digits = self.0.iter();
+
+        Ok(pattern
+            .chars()
+            .map(|current_char| match current_char {
+                '#' => digit_char(*digits.next().unwrap()),
+                _ => current_char,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{DigitSequence, DigitSlice};
+
+impl<'a> DigitSlice<'a> {
+    /// Just like [DigitSequence::groups_from_left].
+    ///
+    /// # Panics
+    ///
+    /// If `group_size` is 0.
+    pub fn groups_from_left(
+        &self,
+        group_size: usize,
+    ) -> impl DoubleEndedIterator<Item = DigitSlice<'a>> + ExactSizeIterator {
+        assert!(group_size > 0, "The group size must be positive");
+
+        self.0.chunks(group_size).map(DigitSlice)
+    }
+
+    /// Just like [DigitSequence::groups_from_right].
+    ///
+    /// # Panics
+    ///
+    /// If `group_size` is 0.
+    pub fn groups_from_right(
+        &self,
+        group_size: usize,
+    ) -> impl DoubleEndedIterator<Item = DigitSlice<'a>> + ExactSizeIterator {
+        assert!(group_size > 0, "The group size must be positive");
+
+        self.0.rchunks(group_size).rev().map(DigitSlice)
+    }
+}
+
+impl DigitSequence {
+    /// Splits the sequence into groups of `group_size` digits, counted from
+    /// the left - so only the last group can be shorter; for example,
+    /// a 16-digit card number consists of four groups of four.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let card_number: DigitSequence = "4111222233334444".parse()?;
+    ///
+    /// let groups: Vec<String> = card_number.groups_from_left(4).map(|group| group.to_string()).collect();
+    /// assert_eq!(groups, ["4111", "2222", "3333", "4444"]);
+    ///
+    /// let sequence: DigitSequence = 1234567u32.into();
+    /// let groups: Vec<DigitSequence> = sequence.groups_from_left(3).map(DigitSequence::from).collect();
+    /// assert_eq!(groups, [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+    ///
+    /// assert_eq!(DigitSequence::new().groups_from_left(3).len(), 0);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `group_size` is 0.
+    pub fn groups_from_left(
+        &self,
+        group_size: usize,
+    ) -> impl DoubleEndedIterator<Item = DigitSlice<'_>> + ExactSizeIterator {
+        self.as_digit_slice().groups_from_left(group_size)
+    }
+
+    /// Splits the sequence into groups of `group_size` digits, counted from
+    /// the right - so only the first group can be shorter, as in the
+    /// thousands of a number; the groups are still yielded from left to right.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence: DigitSequence = 1234567u32.into();
+    ///
+    /// let groups: Vec<String> = sequence.groups_from_right(3).map(|group| group.to_string()).collect();
+    /// assert_eq!(groups, ["1", "234", "567"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `group_size` is 0.
+    pub fn groups_from_right(
+        &self,
+        group_size: usize,
+    ) -> impl DoubleEndedIterator<Item = DigitSlice<'_>> + ExactSizeIterator {
+        self.as_digit_slice().groups_from_right(group_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Splitting into groups" {
+            it "should agree with the grouped display" {
+                for source in ["", "1", "12", "123", "1234", "1234567"] {
+                    let sequence: DigitSequence = source.parse().unwrap();
+
+                    let groups: Vec<String> = sequence.groups_from_right(3).map(|group| group.to_string()).collect();
+
+                    eq!(groups.join(","), sequence.grouped(3, ',').to_string());
+                }
+            }
+
+            it "should be reversible" {
+                let sequence: DigitSequence = 1234567u32.into();
+
+                let groups: Vec<String> = sequence.groups_from_left(3).rev().map(|group| group.to_string()).collect();
+
+                eq!(groups, ["7", "456", "123"]);
+            }
+        }
+    }
+}
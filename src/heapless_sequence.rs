@@ -0,0 +1,344 @@
+use crate::integers::digits_value;
+use crate::{digits_of, CrateError, CrateResult, DigitIter, DigitSequence, DigitSlice};
+use core::fmt::Display;
+use core::str::FromStr;
+
+/// Bounded-capacity counterpart of [DigitSequence], storing up to `N` digits
+/// in a [heapless::Vec] - so that it never allocates, which is handy for
+/// microcontrollers driving 7-segment displays; for *exactly* `N` digits,
+/// please refer to [FixedDigitSequence](crate::FixedDigitSequence).
+///
+/// **REQUIRES FEATURE**: `heapless`.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let display: HeaplessDigitSequence<4> = 907u16.try_into()?;
+/// assert_eq!(display, [9, 0, 7]);
+/// assert_eq!(format!("{:>4}", display), " 907");
+///
+/// let parsed: HeaplessDigitSequence<4> = "0042".parse()?;
+/// assert_eq!(parsed.as_slice(), &[0, 0, 4, 2]);
+///
+/// let sequence: DigitSequence = parsed.clone().into();
+/// assert_eq!(sequence, [0, 0, 4, 2]);
+///
+/// let value: u16 = parsed.try_into()?;
+/// assert_eq!(value, 42);
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Exceeding the capacity results in a [CrateError::Overflow]:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let result: CrateResult<HeaplessDigitSequence<4>> = 90210u32.try_into();
+/// assert_eq!(result, Err(CrateError::Overflow));
+///
+/// let result: CrateResult<HeaplessDigitSequence<2>> = "123".parse();
+/// assert_eq!(result, Err(CrateError::Overflow));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HeaplessDigitSequence<const N: usize>(heapless::Vec<u8, N>);
+
+impl<const N: usize> HeaplessDigitSequence<N> {
+    /// Creates an empty sequence.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence = HeaplessDigitSequence::<8>::new();
+    ///
+    /// assert!(sequence.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        HeaplessDigitSequence(heapless::Vec::new())
+    }
+
+    /// Number of digits in the sequence - never exceeding `N`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(HeaplessDigitSequence::<4>::new().len(), 0);
+    /// assert_eq!("907".parse::<HeaplessDigitSequence<4>>()?.len(), 3);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Tells whether the sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The digits, as a slice.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Borrowed view over the digits, exposing the read-only operations
+    /// of [DigitSlice].
+    pub fn as_digit_slice(&self) -> DigitSlice<'_> {
+        DigitSlice(&self.0)
+    }
+
+    /// Iterates over the digits.
+    pub fn iter(&self) -> DigitIter<'_> {
+        DigitIter(self.0.iter())
+    }
+}
+
+/// A slice of [u8] numbers can be converted to [HeaplessDigitSequence]
+/// as long as its values are 0-9 digits - otherwise, the result is a
+/// [CrateError::NonDigitNumber] - and they fit the capacity.
+impl<const N: usize> TryFrom<&[u8]> for HeaplessDigitSequence<N> {
+    type Error = CrateError;
+
+    fn try_from(digits: &[u8]) -> CrateResult<Self> {
+        if let Some(&digit) = digits.iter().find(|&&digit| digit >= 10) {
+            return Err(CrateError::NonDigitNumber(digit as u128));
+        }
+
+        heapless::Vec::from_slice(digits)
+            .map(HeaplessDigitSequence)
+            .map_err(|_| CrateError::Overflow)
+    }
+}
+
+/// Conversion from a &[DigitSequence] only requires enough capacity.
+impl<const N: usize> TryFrom<&DigitSequence> for HeaplessDigitSequence<N> {
+    type Error = CrateError;
+
+    fn try_from(sequence: &DigitSequence) -> CrateResult<Self> {
+        heapless::Vec::from_slice(&sequence.0)
+            .map(HeaplessDigitSequence)
+            .map_err(|_| CrateError::Overflow)
+    }
+}
+
+/// Conversion from a [DigitSequence] only requires enough capacity.
+impl<const N: usize> TryFrom<DigitSequence> for HeaplessDigitSequence<N> {
+    type Error = CrateError;
+
+    fn try_from(sequence: DigitSequence) -> CrateResult<Self> {
+        (&sequence).try_into()
+    }
+}
+
+/// Conversion to [DigitSequence] is always infallible.
+impl<const N: usize> From<HeaplessDigitSequence<N>> for DigitSequence {
+    fn from(sequence: HeaplessDigitSequence<N>) -> DigitSequence {
+        DigitSequence(sequence.0[..].into())
+    }
+}
+
+/// Parsing works just like for [DigitSequence], as long as the digits fit the capacity.
+impl<const N: usize> FromStr for HeaplessDigitSequence<N> {
+    type Err = CrateError;
+
+    fn from_str(s: &str) -> CrateResult<Self> {
+        let mut digits = heapless::Vec::new();
+
+        for current_char in s.chars() {
+            match current_char {
+                '0'..='9' => digits
+                    .push(current_char as u8 - b'0')
+                    .map_err(|_| CrateError::Overflow)?,
+                _ => return Err(CrateError::NonDigitChar(current_char)),
+            }
+        }
+
+        Ok(HeaplessDigitSequence(digits))
+    }
+}
+
+macro_rules! impl_try_from_unsigned {
+    ($type: ty) => {
+        /// Conversion from an *unsigned* integer to a [HeaplessDigitSequence]
+        /// only fails when the digits exceed the capacity.
+        impl<const N: usize> TryFrom<$type> for HeaplessDigitSequence<N> {
+            type Error = CrateError;
+
+            fn try_from(value: $type) -> CrateResult<Self> {
+                let digits = digits_of(value as u128);
+
+                if digits.len() > N {
+                    return Err(CrateError::Overflow);
+                }
+
+                Ok(HeaplessDigitSequence(digits.collect()))
+            }
+        }
+    };
+}
+
+macro_rules! impl_try_to_unsigned {
+    ($type: ty) => {
+        /// Conversion from a [HeaplessDigitSequence] to an *unsigned* integer
+        /// fails with [CrateError::Overflow] when the value does not fit;
+        /// leading zeros, however, never cause an overflow.
+        impl<const N: usize> TryFrom<HeaplessDigitSequence<N>> for $type {
+            type Error = CrateError;
+
+            fn try_from(sequence: HeaplessDigitSequence<N>) -> CrateResult<Self> {
+                (&sequence).try_into()
+            }
+        }
+
+        /// Conversion from a &[HeaplessDigitSequence] to an *unsigned* integer
+        /// fails with [CrateError::Overflow] when the value does not fit;
+        /// leading zeros, however, never cause an overflow.
+        impl<const N: usize> TryFrom<&HeaplessDigitSequence<N>> for $type {
+            type Error = CrateError;
+
+            fn try_from(sequence: &HeaplessDigitSequence<N>) -> CrateResult<Self> {
+                digits_value(&sequence.0)
+            }
+        }
+    };
+}
+
+impl_try_from_unsigned!(u128);
+impl_try_from_unsigned!(u64);
+impl_try_from_unsigned!(u32);
+impl_try_from_unsigned!(u16);
+impl_try_from_unsigned!(u8);
+impl_try_from_unsigned!(usize);
+
+impl_try_to_unsigned!(u128);
+impl_try_to_unsigned!(u64);
+impl_try_to_unsigned!(u32);
+impl_try_to_unsigned!(u16);
+impl_try_to_unsigned!(u8);
+impl_try_to_unsigned!(usize);
+
+/// The string representation is the same as [DigitSequence] - including
+/// the standard formatting flags and the alternate form - without allocating.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: HeaplessDigitSequence<8> = 1234567u32.try_into()?;
+///
+/// assert_eq!(format!("{:09}", sequence), "001234567");
+/// assert_eq!(format!("{:#}", sequence), "1 234 567");
+///
+/// # Ok(())
+/// # }
+/// ```
+impl<const N: usize> Display for HeaplessDigitSequence<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_digit_slice().fmt(f)
+    }
+}
+
+/// [HeaplessDigitSequence] can be compared with a fixed-size array of [u8].
+impl<const N: usize, const M: usize> PartialEq<[u8; M]> for HeaplessDigitSequence<N> {
+    fn eq(&self, other: &[u8; M]) -> bool {
+        self.0 == *other
+    }
+}
+
+/// [HeaplessDigitSequence] can be compared with a [DigitSequence].
+impl<const N: usize> PartialEq<DigitSequence> for HeaplessDigitSequence<N> {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        self.0 == *other.0
+    }
+}
+
+/// [DigitSequence] can be compared with a [HeaplessDigitSequence].
+impl<const N: usize> PartialEq<HeaplessDigitSequence<N>> for DigitSequence {
+    fn eq(&self, other: &HeaplessDigitSequence<N>) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Converting to a heapless sequence" {
+            it "should convert the largest integer" {
+                let sequence: HeaplessDigitSequence<39> = u128::MAX.try_into().unwrap();
+
+                eq!(sequence, DigitSequence::from(u128::MAX));
+            }
+
+            it "should convert 0" {
+                let sequence: HeaplessDigitSequence<1> = 0u8.try_into().unwrap();
+
+                eq!(sequence, [0]);
+            }
+
+            it "should reject integers exceeding the capacity" {
+                let result: CrateResult<HeaplessDigitSequence<2>> = 100u8.try_into();
+
+                eq!(result, Err(CrateError::Overflow));
+            }
+
+            it "should reject non-digit numbers" {
+                let slice: &[u8] = &[1, 10];
+                let result: CrateResult<HeaplessDigitSequence<4>> = slice.try_into();
+
+                eq!(result, Err(CrateError::NonDigitNumber(10)));
+            }
+
+            it "should convert an owned digit sequence" {
+                let sequence: DigitSequence = 907u16.into();
+                let heapless: HeaplessDigitSequence<3> = sequence.clone().try_into().unwrap();
+
+                eq!(sequence, heapless);
+            }
+        }
+
+        describe "Converting a heapless sequence to an integer" {
+            it "should convert to u128::MAX" {
+                let sequence: HeaplessDigitSequence<39> = u128::MAX.try_into().unwrap();
+                let value: u128 = sequence.try_into().unwrap();
+
+                eq!(value, u128::MAX);
+            }
+
+            it "should ignore leading zeros" {
+                let sequence: HeaplessDigitSequence<8> = "00000255".parse().unwrap();
+                let value: u8 = (&sequence).try_into().unwrap();
+
+                eq!(value, 255);
+            }
+
+            it "should reject values exceeding the target type" {
+                let sequence: HeaplessDigitSequence<4> = 256u16.try_into().unwrap();
+                let result: CrateResult<u8> = sequence.try_into();
+
+                eq!(result, Err(CrateError::Overflow));
+            }
+        }
+
+        describe "Formatting a heapless sequence" {
+            it "should support zero-padding" {
+                let sequence: HeaplessDigitSequence<4> = 7u8.try_into().unwrap();
+
+                eq!(format!("{:04}", sequence), "0007");
+            }
+
+            it "should support the alternate form" {
+                let sequence: HeaplessDigitSequence<8> = 1234567u32.try_into().unwrap();
+
+                eq!(format!("{:#}", sequence), "1 234 567");
+            }
+        }
+    }
+}
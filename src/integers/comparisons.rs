@@ -0,0 +1,90 @@
+use crate::DigitSequence;
+use core::cmp::Ordering;
+
+macro_rules! impl_unsigned_comparisons {
+    ($type: ty) => {
+        /// [DigitSequence] supports equality with *unsigned* integers, comparing
+        /// numeric values - so leading zeros are ignored, and the empty
+        /// sequence equals 0.
+        impl PartialEq<$type> for DigitSequence {
+            fn eq(&self, other: &$type) -> bool {
+                compare_with_unsigned(self, *other as u128) == Ordering::Equal
+            }
+        }
+
+        /// *Unsigned* integers support equality with [DigitSequence],
+        /// comparing numeric values.
+        impl PartialEq<DigitSequence> for $type {
+            fn eq(&self, other: &DigitSequence) -> bool {
+                other == self
+            }
+        }
+
+        /// [DigitSequence] can be ordered with respect to *unsigned* integers,
+        /// comparing numeric values - even when the sequence
+        /// is too long to be converted.
+        impl PartialOrd<$type> for DigitSequence {
+            fn partial_cmp(&self, other: &$type) -> Option<Ordering> {
+                Some(compare_with_unsigned(self, *other as u128))
+            }
+        }
+
+        /// *Unsigned* integers can be ordered with respect to [DigitSequence],
+        /// comparing numeric values.
+        impl PartialOrd<DigitSequence> for $type {
+            fn partial_cmp(&self, other: &DigitSequence) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+/// Compares the numeric value of the sequence with the given integer;
+/// a sequence overflowing [u128] is greater than any integer.
+fn compare_with_unsigned(sequence: &DigitSequence, value: u128) -> Ordering {
+    match u128::try_from(sequence) {
+        Ok(sequence_value) => sequence_value.cmp(&value),
+        Err(_) => Ordering::Greater,
+    }
+}
+
+impl_unsigned_comparisons!(u128);
+impl_unsigned_comparisons!(u64);
+impl_unsigned_comparisons!(u32);
+impl_unsigned_comparisons!(u16);
+impl_unsigned_comparisons!(u8);
+impl_unsigned_comparisons!(usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Comparing with unsigned integers" {
+            it "should ignore leading zeros" {
+                let sequence: DigitSequence = "000387".parse().unwrap();
+
+                eq!(sequence, 387u32);
+                eq!(387u16, sequence);
+                assert!(sequence > 255u8);
+                assert!(sequence < 388u64);
+                assert!(999usize > sequence);
+            }
+
+            it "should treat the empty sequence as 0" {
+                eq!(DigitSequence::new(), 0u8);
+                assert!(DigitSequence::new() < 1u128);
+            }
+
+            it "should consider sequences overflowing u128 greater" {
+                let sequence: DigitSequence = "340282366920938463463374607431768211456".parse().unwrap();
+
+                assert!(sequence > u128::MAX);
+                assert!(u128::MAX < sequence);
+                assert!(sequence != u128::MAX);
+            }
+        }
+    }
+}
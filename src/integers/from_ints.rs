@@ -1,5 +1,4 @@
-use crate::{CrateError, CrateResult, DigitSequence};
-use std::collections::LinkedList;
+use crate::{CrateError, CrateResult, DigitSequence, Digits};
 
 macro_rules! impl_try_from_signed {
     ($type: ty) => {
@@ -32,15 +31,18 @@ macro_rules! impl_from_unsigned {
     };
 }
 
+/// Number of digits of [u128::MAX] - the largest supported integer.
+const MAX_INTEGER_DIGITS: usize = 39;
+
 macro_rules! convert_from_positive {
     ($value: ident) => {{
-        let mut result = LinkedList::new();
+        let mut buffer = [0u8; MAX_INTEGER_DIGITS];
+        let mut start = buffer.len();
         let mut current_value = $value;
 
         loop {
-            let digit = current_value % 10;
-
-            result.push_front(digit as u8);
+            start -= 1;
+            buffer[start] = (current_value % 10) as u8;
 
             current_value /= 10;
 
@@ -49,7 +51,7 @@ macro_rules! convert_from_positive {
             }
         }
 
-        DigitSequence(result.into_iter().collect())
+        DigitSequence(Digits::from(&buffer[start..]))
     }};
 }
 
@@ -84,6 +86,13 @@ mod tests {
                 }
             }
 
+            describe "when converting the largest values" {
+                it "should return all their digits" {
+                    eq!(DigitSequence::from(u128::MAX).to_string(), u128::MAX.to_string());
+                    eq!(DigitSequence::try_from(i128::MAX).unwrap().to_string(), i128::MAX.to_string());
+                }
+            }
+
             describe "when converting a negative number" {
                 it "should return Err" {
                     let result: CrateResult<DigitSequence> = (-4).try_into();
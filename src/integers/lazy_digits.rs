@@ -0,0 +1,115 @@
+use core::iter::FusedIterator;
+
+/// Iterates over the digits of the given unsigned integer - from the most
+/// significant one - without creating a [DigitSequence](crate::DigitSequence):
+/// handy in hot loops that just need to inspect the digits.
+///
+/// Just like the conversion to [DigitSequence](crate::DigitSequence), 0 has exactly one digit.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let digits: Vec<u8> = digits_of(9072u16).collect();
+/// assert_eq!(digits, [9, 0, 7, 2]);
+///
+/// let reversed: Vec<u8> = digits_of(9072u16).rev().collect();
+/// assert_eq!(reversed, [2, 7, 0, 9]);
+///
+/// assert_eq!(digits_of(0u8).collect::<Vec<u8>>(), [0]);
+/// assert_eq!(digits_of(u128::MAX).len(), 39);
+///
+/// let digit_sum: u32 = digits_of(987654321u32).map(u32::from).sum();
+/// assert_eq!(digit_sum, 45);
+/// ```
+pub fn digits_of(value: impl Into<u128>) -> IntegerDigits {
+    let value = value.into();
+    let len = value.checked_ilog10().unwrap_or(0) + 1;
+
+    IntegerDigits {
+        value,
+        front: 0,
+        back: len,
+        len,
+    }
+}
+
+/// Iterator over the digits of an integer, returned by [digits_of].
+#[derive(Debug, Clone)]
+pub struct IntegerDigits {
+    value: u128,
+    front: u32,
+    back: u32,
+    len: u32,
+}
+
+impl IntegerDigits {
+    fn digit_at(&self, index: u32) -> u8 {
+        (self.value / 10u128.pow(self.len - 1 - index) % 10) as u8
+    }
+}
+
+impl Iterator for IntegerDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let digit = self.digit_at(self.front);
+        self.front += 1;
+
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front) as usize;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for IntegerDigits {
+    fn next_back(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(self.digit_at(self.back))
+    }
+}
+
+impl ExactSizeIterator for IntegerDigits {}
+
+impl FusedIterator for IntegerDigits {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitSequence;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Lazy integer digits" {
+            it "should match the conversion to DigitSequence" {
+                for value in [0u128, 1, 9, 10, 99, 100, 1234567890, u64::MAX as u128, u128::MAX] {
+                    let digits: Vec<u8> = digits_of(value).collect();
+
+                    eq!(DigitSequence::from(value), digits);
+                }
+            }
+
+            it "should support iterating from both ends" {
+                let mut digits = digits_of(12345u16);
+
+                eq!(digits.next(), Some(1));
+                eq!(digits.next_back(), Some(5));
+                eq!(digits.len(), 3);
+                eq!(digits.collect::<Vec<u8>>(), vec![2, 3, 4]);
+            }
+        }
+    }
+}
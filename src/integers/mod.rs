@@ -1,2 +1,7 @@
+mod comparisons;
 mod from_ints;
+mod lazy_digits;
 mod to_ints;
+
+pub use lazy_digits::*;
+pub(crate) use to_ints::digits_value;
@@ -1,12 +1,47 @@
+use crate::arithmetic::significant_digits;
 use crate::{CrateError, CrateResult, DigitSequence};
 
+/// Unsigned integer type into which digits can be folded.
+pub(crate) trait DigitsValue: Sized {
+    /// Number of digits of the largest value.
+    const MAX_DIGITS: usize;
+
+    /// Appends the given digit to the value - that is, `value * 10 + digit` -
+    /// or returns [None] on overflow.
+    fn append_digit(self, digit: u8) -> Option<Self>;
+}
+
+/// Numeric value of the given digits, folded directly into the target type -
+/// which results in a [CrateError::Overflow] if it does not fit; leading zeros
+/// never cause an overflow, and no digits at all represent 0.
+pub(crate) fn digits_value<T: DigitsValue + Default>(digits: &[u8]) -> CrateResult<T> {
+    let digits = significant_digits(digits);
+
+    if digits.len() > T::MAX_DIGITS {
+        return Err(CrateError::Overflow);
+    }
+
+    digits.iter().try_fold(T::default(), |result, &digit| {
+        result.append_digit(digit).ok_or(CrateError::Overflow)
+    })
+}
+
 macro_rules! impl_try_to_unsigned {
     ($type: ty) => {
+        impl DigitsValue for $type {
+            const MAX_DIGITS: usize = <$type>::MAX.ilog10() as usize + 1;
+
+            fn append_digit(self, digit: u8) -> Option<Self> {
+                self.checked_mul(10)?.checked_add(digit as $type)
+            }
+        }
+
         /// Conversion from a [DigitSequence] is only available to
         /// *unsigned* integers.
         ///
         /// It is always fallible - because
-        /// it might result in a [CrateError::Overflow].
+        /// it might result in a [CrateError::Overflow];
+        /// leading zeros, however, never cause an overflow.
         impl TryFrom<DigitSequence> for $type {
             type Error = CrateError;
 
@@ -19,32 +54,13 @@ macro_rules! impl_try_to_unsigned {
         /// *unsigned* integers.
         ///
         /// It is always fallible - because
-        /// it might result in a [CrateError::Overflow].
+        /// it might result in a [CrateError::Overflow];
+        /// leading zeros, however, never cause an overflow.
         impl TryFrom<&DigitSequence> for $type {
             type Error = CrateError;
 
             fn try_from(sequence: &DigitSequence) -> CrateResult<Self> {
-                let mut result = 0 as Self;
-
-                let enumerated_increasing_digits = sequence.iter().rev().enumerate();
-
-                for (index, &digit) in enumerated_increasing_digits {
-                    let power_of_ten: u32 = index.try_into().or(Err(CrateError::Overflow))?;
-
-                    let magnitude = (10 as Self)
-                        .checked_pow(power_of_ten)
-                        .ok_or(CrateError::Overflow)?;
-
-                    let addition_term = (digit as Self)
-                        .checked_mul(magnitude)
-                        .ok_or(CrateError::Overflow)?;
-
-                    result = result
-                        .checked_add(addition_term)
-                        .ok_or(CrateError::Overflow)?;
-                }
-
-                Ok(result)
+                digits_value(&sequence.0)
             }
         }
     };
@@ -60,7 +76,7 @@ impl_try_to_unsigned!(usize);
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::*;
+    use crate::testing::*;
     use pretty_assertions::assert_eq as eq;
     use speculate2::*;
 
@@ -113,6 +129,33 @@ mod tests {
                 test_case_overflow_u128("1".repeat(100).as_str());
             }
 
+            it "should convert a value padded with many leading zeros" {
+                let sequence: DigitSequence = format!("{}{}", "0".repeat(100), u128::MAX).parse().unwrap();
+                let conversion_result: CrateResult<u128> = sequence.try_into();
+
+                eq!(conversion_result, Ok(u128::MAX));
+            }
+
+            it "should convert the empty sequence to 0" {
+                eq!(u8::try_from(DigitSequence::new()), Ok(0));
+            }
+
+            it "should NOT convert values beyond a narrow type" {
+                for source in ["256", "0001000", "99999"] {
+                    let sequence: DigitSequence = source.parse().unwrap();
+                    let conversion_result: CrateResult<u8> = sequence.try_into();
+
+                    eq!(conversion_result, Err(CrateError::Overflow));
+                }
+            }
+
+            it "should convert the largest value of a narrow type, even with leading zeros" {
+                let sequence: DigitSequence = "000255".parse().unwrap();
+                let conversion_result: CrateResult<u8> = sequence.try_into();
+
+                eq!(conversion_result, Ok(255));
+            }
+
             it "should NOT convert a 1 of huge magnitude" {
                 test_case_overflow_u128(&format!("1{}", "0".repeat(100)));
             }
@@ -167,6 +210,18 @@ mod tests {
                 test_case_overflow_u128_via_ref(&"1".repeat(100));
             }
 
+            it "should convert a value padded with many leading zeros" {
+                let sequence: DigitSequence = format!("{}{}", "0".repeat(100), u128::MAX).parse().unwrap();
+                let reference = &sequence;
+                let conversion_result: CrateResult<u128> = reference.try_into();
+
+                eq!(conversion_result, Ok(u128::MAX));
+            }
+
+            it "should convert the empty sequence to 0" {
+                eq!(u8::try_from(&DigitSequence::new()), Ok(0));
+            }
+
             it "should NOT convert a 1 of huge magnitude" {
                 test_case_overflow_u128_via_ref(&format!("1{}", "0".repeat(100)));
             }
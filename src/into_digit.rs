@@ -0,0 +1,83 @@
+use crate::{CrateError, CrateResult};
+
+/// Value that might represent a 0-9 digit - such as any primitive integer,
+/// a [char], or a reference to them - as accepted by
+/// [DigitSequence::try_from_iter](crate::DigitSequence::try_from_iter).
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// assert_eq!(7u64.into_digit(), Ok(7));
+/// assert_eq!((&3i8).into_digit(), Ok(3));
+/// assert_eq!(10u16.into_digit(), Err(CrateError::NonDigitNumber(10)));
+/// assert_eq!((-1i32).into_digit(), Err(CrateError::NegativeNumber(-1)));
+/// assert_eq!('8'.into_digit(), Ok(8));
+/// assert_eq!('x'.into_digit(), Err(CrateError::NonDigitChar('x')));
+/// ```
+pub trait IntoDigit {
+    /// Converts the value to a 0-9 digit - or returns a [CrateError::NonDigitNumber],
+    /// a [CrateError::NegativeNumber] for negative values
+    /// or a [CrateError::NonDigitChar] for chars.
+    fn into_digit(self) -> CrateResult<u8>;
+}
+
+macro_rules! impl_into_digit_unsigned {
+    ($type: ty) => {
+        impl IntoDigit for $type {
+            fn into_digit(self) -> CrateResult<u8> {
+                if self < 10 {
+                    Ok(self as u8)
+                } else {
+                    Err(CrateError::NonDigitNumber(self as u128))
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_into_digit_signed {
+    ($type: ty) => {
+        impl IntoDigit for $type {
+            fn into_digit(self) -> CrateResult<u8> {
+                if self < 0 {
+                    Err(CrateError::NegativeNumber(self as i128))
+                } else if self < 10 {
+                    Ok(self as u8)
+                } else {
+                    Err(CrateError::NonDigitNumber(self as u128))
+                }
+            }
+        }
+    };
+}
+
+impl_into_digit_unsigned!(u8);
+impl_into_digit_unsigned!(u16);
+impl_into_digit_unsigned!(u32);
+impl_into_digit_unsigned!(u64);
+impl_into_digit_unsigned!(u128);
+impl_into_digit_unsigned!(usize);
+
+impl_into_digit_signed!(i8);
+impl_into_digit_signed!(i16);
+impl_into_digit_signed!(i32);
+impl_into_digit_signed!(i64);
+impl_into_digit_signed!(i128);
+impl_into_digit_signed!(isize);
+
+/// Only the ASCII digits are accepted - see [DigitSequence::parse_unicode](crate::DigitSequence::parse_unicode)
+/// for the other Unicode decimal digits.
+impl IntoDigit for char {
+    fn into_digit(self) -> CrateResult<u8> {
+        match self {
+            '0'..='9' => Ok(self as u8 - b'0'),
+            _ => Err(CrateError::NonDigitChar(self)),
+        }
+    }
+}
+
+impl<T: IntoDigit + Copy> IntoDigit for &T {
+    fn into_digit(self) -> CrateResult<u8> {
+        (*self).into_digit()
+    }
+}
@@ -1,4 +1,6 @@
-use crate::DigitSequence;
+use crate::integers::digits_value;
+use crate::{CrateResult, DigitSequence, DigitSlice, Digits, IntoDigit};
+use core::iter::FusedIterator;
 
 impl DigitSequence {
     /// Repeatable iteration over references to the digits.
@@ -26,8 +28,103 @@ impl DigitSequence {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn iter(&self) -> std::slice::Iter<u8> {
-        self.0.iter()
+    pub fn iter(&self) -> DigitIter<'_> {
+        DigitIter(self.0.iter())
+    }
+
+    /// Tells whether the sequence starts with the digits of the given prefix;
+    /// the empty sequence is a prefix of any sequence.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = [3, 9, 0, 2].try_into()?;
+    ///
+    /// assert!(sequence.starts_with(&[3, 9].try_into()?));
+    /// assert!(sequence.starts_with(&sequence));
+    /// assert!(sequence.starts_with(&DigitSequence::new()));
+    /// assert!(!sequence.starts_with(&[9].try_into()?));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn starts_with(&self, prefix: &DigitSequence) -> bool {
+        self.0.starts_with(&prefix.0)
+    }
+
+    /// Tells whether the sequence ends with the digits of the given suffix;
+    /// the empty sequence is a suffix of any sequence.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = [3, 9, 0, 2].try_into()?;
+    ///
+    /// assert!(sequence.ends_with(&[0, 2].try_into()?));
+    /// assert!(sequence.ends_with(&sequence));
+    /// assert!(sequence.ends_with(&DigitSequence::new()));
+    /// assert!(!sequence.ends_with(&[3].try_into()?));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ends_with(&self, suffix: &DigitSequence) -> bool {
+        self.0.ends_with(&suffix.0)
+    }
+
+    /// Creates a sequence from any source of [IntoDigit] items - such as
+    /// integers of any type, or references to them - stopping at the
+    /// first item that is not a 0-9 digit, whose error is returned.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use std::collections::VecDeque;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let values: Vec<u64> = vec![9, 0, 2];
+    /// assert_eq!(DigitSequence::try_from_iter(&values)?, [9, 0, 2]);
+    ///
+    /// let queue = VecDeque::from([4i32, 7]);
+    /// assert_eq!(DigitSequence::try_from_iter(queue)?, [4, 7]);
+    ///
+    /// let doubled = DigitSequence::try_from_iter((1u8..=4).map(|digit| digit * 2))?;
+    /// assert_eq!(doubled, [2, 4, 6, 8]);
+    ///
+    /// assert_eq!(DigitSequence::try_from_iter([1u16, 20]), Err(CrateError::NonDigitNumber(20)));
+    /// assert_eq!(DigitSequence::try_from_iter([1i64, -2]), Err(CrateError::NegativeNumber(-2)));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Chars are supported as well - so that, for example, the filtered chars
+    /// of user-supplied text can be converted with no intermediate [String],
+    /// while still rejecting anything but ASCII digits:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let card_number = DigitSequence::try_from_iter("4111-1111".chars().filter(|&c| c != '-'))?;
+    /// assert_eq!(card_number, [4, 1, 1, 1, 1, 1, 1, 1]);
+    ///
+    /// let result = DigitSequence::try_from_iter("12a4".chars());
+    /// assert_eq!(result, Err(CrateError::NonDigitChar('a')));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_iter<I>(values: I) -> CrateResult<DigitSequence>
+    where
+        I: IntoIterator<Item: IntoDigit>,
+    {
+        values
+            .into_iter()
+            .map(IntoDigit::into_digit)
+            .collect::<CrateResult<Digits>>()
+            .map(DigitSequence)
     }
 }
 
@@ -74,11 +171,17 @@ impl DigitSequence {
 impl IntoIterator for DigitSequence {
     type Item = u8;
 
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
+    #[cfg(not(feature = "smallvec"))]
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
     }
+
+    #[cfg(feature = "smallvec")]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_vec().into_iter()
+    }
 }
 
 /// Repeatable, reference-based iteration on &[DigitSequence] is supported.
@@ -109,9 +212,124 @@ impl IntoIterator for DigitSequence {
 impl<'a> IntoIterator for &'a DigitSequence {
     type Item = &'a u8;
 
-    type IntoIter = std::slice::Iter<'a, u8>;
+    type IntoIter = DigitIter<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+        self.iter()
+    }
+}
+
+/// Iterator over references to the digits of a [DigitSequence] or of a
+/// [DigitSlice], returned by their `iter()` methods - with helpers
+/// that consider the digits not yet consumed from either end.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: DigitSequence = "0390725".parse()?;
+/// let mut digits = sequence.iter();
+///
+/// digits.next();
+/// digits.next_back();
+///
+/// assert_eq!(digits.as_slice(), [3, 9, 0, 7, 2]);
+/// assert_eq!(digits.remaining_value()?, 39072);
+/// assert_eq!(digits.digit_sum(), 21);
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DigitIter<'a>(pub(crate) core::slice::Iter<'a, u8>);
+
+impl<'a> DigitIter<'a> {
+    /// The digits not consumed yet.
+    pub fn as_slice(&self) -> DigitSlice<'a> {
+        DigitSlice(self.0.as_slice())
+    }
+
+    /// The numeric value of the digits not consumed yet - which results
+    /// in a [CrateError::Overflow](crate::CrateError::Overflow) if it does not fit into a [u128];
+    /// just like converting a [DigitSequence], leading zeros never cause
+    /// an overflow, and no digits at all represent 0.
+    pub fn remaining_value(&self) -> CrateResult<u128> {
+        digits_value(self.0.as_slice())
+    }
+
+    /// The sum of the digits not consumed yet - without consuming them;
+    /// unlike [Iterator::sum], it cannot overflow.
+    pub fn digit_sum(&self) -> u64 {
+        self.0.as_slice().iter().map(|&digit| digit as u64).sum()
+    }
+}
+
+impl<'a> Iterator for DigitIter<'a> {
+    type Item = &'a u8;
+
+    fn next(&mut self) -> Option<&'a u8> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<&'a u8> {
+        self.0.nth(n)
+    }
+}
+
+impl DoubleEndedIterator for DigitIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth_back(n)
+    }
+}
+
+impl ExactSizeIterator for DigitIter<'_> {}
+
+impl FusedIterator for DigitIter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CrateError;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Reference iteration" {
+            it "should compute the remaining value, even past u128" {
+                let sequence: DigitSequence = format!("1{}", u128::MAX).parse().unwrap();
+                let mut digits = sequence.iter();
+
+                eq!(digits.remaining_value(), Err(CrateError::Overflow));
+
+                digits.next();
+                eq!(digits.remaining_value(), Ok(u128::MAX));
+
+                digits.by_ref().for_each(drop);
+                eq!(digits.remaining_value(), Ok(0));
+            }
+        }
+
+        describe "Consuming iteration" {
+            it "should yield every digit, whatever the length" {
+                for length in [0, 1, 24, 25, 60] {
+                    let source = "9081726354".repeat(6)[..length].to_string();
+                    let sequence: DigitSequence = source.parse().unwrap();
+
+                    #[cfg(feature = "smallvec")]
+                    eq!(sequence.0.spilled(), length > crate::INLINE_DIGITS);
+
+                    let digits: String = sequence.into_iter().map(|digit| char::from(b'0' + digit)).collect();
+                    eq!(digits, source);
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,44 @@
+use crate::DigitSequence;
+use alloc::borrow::Cow;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// With the `schemars` feature, [DigitSequence] is described
+/// as an array of integers from 0 to 9.
+///
+/// **REQUIRES FEATURE**: `schemars`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use schemars::schema_for;
+///
+/// let schema = schema_for!(DigitSequence);
+/// let items = &schema.as_value()["items"];
+///
+/// assert_eq!(schema.as_value()["type"], "array");
+/// assert_eq!(items["minimum"], 0);
+/// assert_eq!(items["maximum"], 9);
+/// ```
+///
+/// Fields serialized via [serde_str](crate::serde_str) should rather refer to
+/// its dedicated [schema](crate::serde_str::json_schema), describing a digit string.
+impl JsonSchema for DigitSequence {
+    fn schema_name() -> Cow<'static, str> {
+        "DigitSequence".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        concat!(module_path!(), "::DigitSequence").into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "Sequence of 0-9 digits",
+            "type": "array",
+            "items": {
+                "type": "integer",
+                "minimum": 0,
+                "maximum": 9
+            }
+        })
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{CrateError, CrateResult, DigitSequence, Digits};
+use alloc::vec::Vec;
+
+/// The letters printed on each key of a phone keypad, as per ITU E.161.
+const KEYPAD_LETTERS: [&str; 10] = [
+    "", "", "ABC", "DEF", "GHI", "JKL", "MNO", "PQRS", "TUV", "WXYZ",
+];
+
+impl DigitSequence {
+    /// The letters printed on the phone keypad key of each digit -
+    /// as per ITU E.161; 0 and 1 have no letters.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence: DigitSequence = 2107u16.into();
+    ///
+    /// assert_eq!(sequence.to_keypad_letters(), ["ABC", "", "", "PQRS"]);
+    /// ```
+    pub fn to_keypad_letters(&self) -> Vec<&'static str> {
+        self.0
+            .iter()
+            .map(|&digit| KEYPAD_LETTERS[digit as usize])
+            .collect()
+    }
+
+    /// Converts a vanity phone number - made of ASCII digits and letters,
+    /// in any case - to the digits of the matching keypad keys;
+    /// any other character results in a [CrateError::NonDigitChar].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(DigitSequence::from_vanity("FLOWERS")?, [3, 5, 6, 9, 3, 7, 7]);
+    /// assert_eq!(DigitSequence::from_vanity("1800flowers")?.to_string(), "18003569377");
+    ///
+    /// assert_eq!(
+    ///     DigitSequence::from_vanity("1-800-FLOWERS"),
+    ///     Err(CrateError::NonDigitChar('-'))
+    /// );
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_vanity(s: &str) -> CrateResult<DigitSequence> {
+        s.chars()
+            .map(|current_char| match current_char {
+                '0'..='9' => Ok(current_char as u8 - b'0'),
+                _ => keypad_digit(current_char).ok_or(CrateError::NonDigitChar(current_char)),
+            })
+            .collect::<CrateResult<Digits>>()
+            .map(DigitSequence)
+    }
+}
+
+/// The keypad digit whose key shows the given letter, if any.
+fn keypad_digit(letter: char) -> Option<u8> {
+    let letter = letter.to_ascii_uppercase();
+
+    KEYPAD_LETTERS
+        .iter()
+        .position(|letters| letters.contains(letter))
+        .map(|digit| digit as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Phone keypad" {
+            it "should map every letter to the key showing it" {
+                for letter in 'a'..='z' {
+                    let sequence = DigitSequence::from_vanity(&letter.to_string()).unwrap();
+
+                    assert!(sequence.to_keypad_letters()[0].contains(letter.to_ascii_uppercase()));
+                }
+            }
+
+            it "should reject non-ASCII letters" {
+                eq!(DigitSequence::from_vanity("À"), Err(CrateError::NonDigitChar('À')));
+            }
+        }
+    }
+}
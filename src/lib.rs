@@ -1,9 +1,31 @@
 //! This crate revolves around the [DigitSequence] struct,
 //! a sequence of 0-9 [u8] digits, with:
 //!
-//! * conversions from/to integers, numeric sequences and strings
+//! * conversions from/to integers, numeric sequences and strings - plus
+//!   [allocation-free iteration](digits_of) over the digits of integers
 //!
-//! * different iteration strategies
+//! * borrowed, read-only views via [DigitSlice], and cheap-clone sharing via [SharedDigitSequence]
+//!
+//! * [ChunkedDigitSequence], for hundreds of millions of digits without a single contiguous allocation
+//!
+//! * stack-only, fixed-width sequences - such as PINs - via [FixedDigitSequence]
+//!
+//! * compact nibble packing, with hex and base64 text forms - as well as
+//!   [PackedDigitSequence], storing two digits per byte
+//!
+//! * formatting helpers, such as masking, English spell-out and Roman numerals
+//!
+//! * different iteration strategies - and the [DigitStream] trait, sharing
+//!   algorithms between in-memory sequences and unbounded digit sources,
+//!   which can also be [concatenated](ConcatDigits)
+//!
+//! * digit-based arithmetic and number-theory predicates
+//!
+//! * digit statistics
+//!
+//! * [check-digit algorithms](checksum) and card brand detection
+//!
+//! * digit-based sequences, such as look-and-say - and the digits of [constants], such as π, e, √2 and Champernowne's
 //!
 //! * a custom [CrateResult] and a custom [CrateError]
 //!
@@ -13,20 +35,156 @@
 //!
 //! This crate supports the following _optional_ features:
 //!
-//! - `serde`: enables JSON conversions via [serde](https://crates.io/crates/serde)
+//! - `serde`: enables JSON conversions via [serde](https://crates.io/crates/serde) - also [as strings](serde_str) or [flexibly](serde_flexible)
+//!
+//! - `primality`: enables [Miller-Rabin](DigitSequence::is_probably_prime) primality testing
+//!
+//! - `otp`: enables [one-time password](otp) generation
+//!
+//! - `rand`: enables [random](DigitSequence::random) sequence generation - as well as [random digit streams](RandomDigits) - via [rand](https://crates.io/crates/rand)
+//!
+//! - `miette`: makes [CrateError] a diagnostic for [miette](https://crates.io/crates/miette)
+//!
+//! - `schemars`: describes [DigitSequence] via JSON Schema, thanks to [schemars](https://crates.io/crates/schemars)
+//!
+//! - `utoipa`: describes [DigitSequence] in OpenAPI documents, thanks to [utoipa](https://crates.io/crates/utoipa)
+//!
+//! - `sqlx-postgres`: maps [DigitSequence] to Postgres columns via [sqlx](https://crates.io/crates/sqlx)
+//!
+//! - `redis`: makes [DigitSequence] usable as a [redis](https://crates.io/crates/redis) argument and value
+//!
+//! - `proptest`: provides [strategies] for property-based testing via [proptest](https://crates.io/crates/proptest)
+//!
+//! - `quickcheck`: makes [DigitSequence] arbitrary - with shrinking - for [quickcheck](https://crates.io/crates/quickcheck)
+//!
+//! - `heapless`: provides the allocation-free [HeaplessDigitSequence], via [heapless](https://crates.io/crates/heapless)
+//!
+//! - `mmap`: reads huge files of digits via [memory mapping](MappedDigitFile), thanks to [memmap2](https://crates.io/crates/memmap2)
+//!
+//! - `smallvec`: stores up to 24 digits inline - so that short sequences such as phone numbers, card numbers and OTPs do not allocate - via [smallvec](https://crates.io/crates/smallvec)
+//!
+//! - `rayon`: computes [statistics](DigitSequence::par_digit_counts) and [parses](DigitSequence::par_from_ascii) huge sequences on all the cores, also enabling parallel iteration, via [rayon](https://crates.io/crates/rayon)
+//!
+//! - `chrono`: converts [dates and times](DigitSequence::from_date_time) from/to digits, via [chrono](https://crates.io/crates/chrono)
+//!
+//! - `macros`: provides [dseq!], parsing string literals into [DigitSequence] at compile time
+//!
+//! - `testing`: provides round-trip assertions and fixtures for the [testing] of downstream crates
+//!
+//! The `std` feature is enabled by default; without it, the crate is `no_std` - only
+//! requiring [alloc](https://doc.rust-lang.org/alloc/) - so that, together with `heapless`,
+//! it can run on microcontrollers; I/O and [SystemTime](std::time::SystemTime) conversions,
+//! as well as the features relying on third-party `std` crates, do require `std`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
+mod arithmetic;
 mod arrays;
+mod cards;
+mod chars;
+pub mod checksum;
+mod chunked;
+pub mod constants;
+#[cfg(feature = "chrono")]
+mod dates;
+mod digit_slice;
+mod fixed;
+mod formatting;
+mod groups;
+#[cfg(feature = "heapless")]
+mod heapless_sequence;
 mod integers;
+mod into_digit;
 mod iteration;
+#[cfg(feature = "schemars")]
+mod json_schema;
+mod keypad;
+mod look_and_say;
+mod macros;
+#[cfg(feature = "mmap")]
+mod mapped;
+mod non_empty;
+mod normalized;
+mod number_theory;
+mod numeric_ord;
+#[cfg(feature = "utoipa")]
+mod openapi;
+#[cfg(feature = "otp")]
+pub mod otp;
+mod packed;
+mod packing;
+mod padding;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod phone;
+mod place_values;
+#[cfg(feature = "sqlx-postgres")]
+mod postgres;
+#[cfg(feature = "primality")]
+mod primality;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "redis")]
+mod redis;
 mod result;
+mod roman;
+mod run_length;
+mod secret;
+#[cfg(feature = "serde")]
+pub mod serde_flexible;
+#[cfg(feature = "serde")]
+pub mod serde_str;
+#[cfg(feature = "serde")]
+mod serialization;
+mod shared;
 mod slices;
+mod statistics;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+mod stream;
 mod strings;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+#[cfg(feature = "std")]
+mod timestamps;
+mod trimming;
 mod vecs;
+mod words;
 
-#[cfg(test)]
-pub mod test_utils;
-
+pub use cards::*;
+pub use chunked::*;
+#[cfg(feature = "macros")]
+pub use digit_sequence_macros::dseq;
+pub use digit_slice::*;
+pub use fixed::*;
+pub use formatting::*;
+#[cfg(feature = "heapless")]
+pub use heapless_sequence::*;
+pub use integers::*;
+pub use into_digit::*;
+pub use iteration::*;
+pub use look_and_say::*;
+pub use macros::*;
+#[cfg(feature = "mmap")]
+pub use mapped::*;
+pub use non_empty::*;
+pub use normalized::*;
+pub use numeric_ord::*;
+pub use packed::*;
+#[cfg(feature = "rand")]
+pub use random::*;
 pub use result::*;
+pub use secret::*;
+pub use shared::*;
+pub use statistics::*;
+pub use stream::*;
+pub use strings::*;
+#[cfg(feature = "std")]
+pub use timestamps::*;
 
 /// Immutable sequence of [u8] digits.
 ///
@@ -54,6 +212,15 @@ pub use result::*;
 /// # }
 /// ```
 ///
+/// Literal digits can also be passed to the [digits!] macro,
+/// which validates them at compile time:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// assert_eq!(digits![3, 8, 7], [3, 8, 7]);
+/// ```
+///
 /// For details and more code samples, please refer to the
 /// implementations of the [From] and [TryFrom] interfaces.
 ///
@@ -110,6 +277,9 @@ pub use result::*;
 ///
 /// When the `serde` feature is enabled for this crate, [DigitSequence] implements the [serde::Serialize] and [serde::Deserialize] traits.
 ///
+/// Deserialization rejects any item that is not a 0-9 digit; binary formats
+/// store the [packed](DigitSequence::pack) digits.
+///
 /// ```
 /// #[cfg(feature = "my_feature")]
 /// {
@@ -131,8 +301,19 @@ pub use result::*;
 /// }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct DigitSequence(pub(crate) Vec<u8>);
+pub struct DigitSequence(pub(crate) Digits);
+
+/// Number of digits stored without allocating, when the `smallvec` feature is enabled.
+#[cfg(feature = "smallvec")]
+pub(crate) const INLINE_DIGITS: usize = 24;
+
+/// Internal storage of [DigitSequence].
+#[cfg(not(feature = "smallvec"))]
+pub(crate) type Digits = alloc::vec::Vec<u8>;
+
+/// Internal storage of [DigitSequence] - inline up to [INLINE_DIGITS], then spilling to the heap.
+#[cfg(feature = "smallvec")]
+pub(crate) type Digits = smallvec::SmallVec<[u8; INLINE_DIGITS]>;
 
 impl DigitSequence {
     /// Creates an empty sequence.
@@ -144,8 +325,18 @@ impl DigitSequence {
     ///
     /// assert_eq!(sequence.iter().len(), 0);
     /// ```
-    pub fn new() -> DigitSequence {
-        DigitSequence(vec![])
+    ///
+    /// It is also available in const contexts:
+    ///
+    /// ```
+    /// use digit_sequence::DigitSequence;
+    ///
+    /// static EMPTY: DigitSequence = DigitSequence::new();
+    ///
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    pub const fn new() -> DigitSequence {
+        DigitSequence(empty_digits())
     }
 
     /// Tells whether the sequence is empty.
@@ -162,4 +353,80 @@ impl DigitSequence {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Releases the unused capacity of the sequence - for example, before
+    /// storing it in a long-lived cache; when the `smallvec` feature is enabled,
+    /// short enough sequences are moved back inline.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let mut sequence = DigitSequence::try_from_iter(
+    ///     (0..1000)
+    ///         .map(|index| index % 10)
+    ///         .filter(|_| true),
+    /// )?;
+    /// assert!(sequence.heap_size_in_bytes() >= 1000);
+    ///
+    /// sequence.shrink_to_fit();
+    /// assert_eq!(sequence.heap_size_in_bytes(), 1000);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    /// Number of bytes allocated on the heap by the sequence - including any
+    /// unused capacity, but excluding the `size_of::<DigitSequence>()` bytes of the
+    /// struct itself; it is 0 for sequences stored inline via the `smallvec` feature.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(DigitSequence::new().heap_size_in_bytes(), 0);
+    ///
+    /// let mut sequence: DigitSequence = "9".repeat(100).parse()?;
+    /// assert!(sequence.heap_size_in_bytes() >= 100);
+    ///
+    /// sequence.shrink_to_fit();
+    /// assert_eq!(sequence.heap_size_in_bytes(), 100);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn heap_size_in_bytes(&self) -> usize {
+        heap_capacity(&self.0)
+    }
+}
+
+/// Empty storage, created in const contexts.
+#[cfg(not(feature = "smallvec"))]
+const fn empty_digits() -> Digits {
+    alloc::vec::Vec::new()
+}
+
+/// Empty storage, created in const contexts.
+#[cfg(feature = "smallvec")]
+const fn empty_digits() -> Digits {
+    Digits::new_const()
+}
+
+/// Capacity of the heap buffer backing the digits.
+#[cfg(not(feature = "smallvec"))]
+fn heap_capacity(digits: &Digits) -> usize {
+    digits.capacity()
+}
+
+/// Capacity of the heap buffer backing the digits - if they have spilled.
+#[cfg(feature = "smallvec")]
+fn heap_capacity(digits: &Digits) -> usize {
+    if digits.spilled() {
+        digits.capacity()
+    } else {
+        0
+    }
 }
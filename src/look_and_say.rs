@@ -0,0 +1,93 @@
+use crate::{DigitSequence, Digits};
+
+impl DigitSequence {
+    /// The next term of the *look-and-say* sequence - obtained by reading
+    /// aloud each run of equal digits as its length followed by the digit.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 1211u16.into();
+    /// assert_eq!(sequence.look_and_say(), [1, 1, 1, 2, 2, 1]);
+    ///
+    /// let long_run = DigitSequence::repdigit(3, 12)?;
+    /// assert_eq!(long_run.look_and_say(), [1, 2, 3]);
+    ///
+    /// assert_eq!(DigitSequence::new().look_and_say(), []);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn look_and_say(&self) -> DigitSequence {
+        let mut result = Digits::with_capacity(self.0.len() * 2);
+
+        for (digit, run_length) in self.run_length_encode() {
+            result.extend(DigitSequence::from(run_length).0);
+            result.push(digit);
+        }
+
+        DigitSequence(result)
+    }
+}
+
+/// Infinite iterator over the terms of the *look-and-say* sequence,
+/// starting from any seed.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let terms: Vec<String> = LookAndSay::new(1u8.into())
+///     .take(6)
+///     .map(|term| term.to_string())
+///     .collect();
+///
+/// assert_eq!(terms, vec!["1", "11", "21", "1211", "111221", "312211"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LookAndSay {
+    next_term: DigitSequence,
+}
+
+impl LookAndSay {
+    /// Creates an iterator whose first item is the given seed.
+    pub fn new(seed: DigitSequence) -> LookAndSay {
+        LookAndSay { next_term: seed }
+    }
+}
+
+impl Iterator for LookAndSay {
+    type Item = DigitSequence;
+
+    fn next(&mut self) -> Option<DigitSequence> {
+        let following_term = self.next_term.look_and_say();
+
+        Some(core::mem::replace(&mut self.next_term, following_term))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Iterating over the look-and-say sequence" {
+            it "should grow beyond u128" {
+                let term = LookAndSay::new(1u8.into()).nth(60).unwrap();
+
+                assert!(term.iter().len() > 39);
+                assert!(u128::try_from(&term).is_err());
+            }
+
+            it "should support the self-describing seed 22" {
+                let seed: DigitSequence = 22u8.into();
+
+                let terms: Vec<DigitSequence> = LookAndSay::new(seed.clone()).take(3).collect();
+
+                eq!(terms, vec![seed.clone(), seed.clone(), seed]);
+            }
+        }
+    }
+}
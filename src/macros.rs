@@ -0,0 +1,60 @@
+/// Creates a [DigitSequence](crate::DigitSequence) from digit literals -
+/// infallibly, because every item is validated at compile time.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let sequence = digits![3, 8, 7];
+/// assert_eq!(sequence, [3, 8, 7]);
+///
+/// assert_eq!(digits![], DigitSequence::new());
+/// ```
+///
+/// Any item that is not a 0-9 digit fails the build:
+///
+/// ```compile_fail
+/// use digit_sequence::*;
+///
+/// let sequence = digits![3, 10, 7];
+/// ```
+#[macro_export]
+macro_rules! digits {
+    ($($digit:expr),* $(,)?) => {{
+        const DIGITS: &[u8] = &[$($digit),*];
+        const _: () = $crate::assert_digit_literals(DIGITS);
+
+        // SAFETY: the digits have been validated at compile time
+        unsafe { $crate::DigitSequence::from_vec_unchecked(DIGITS.to_vec()) }
+    }};
+}
+
+/// Validates the items passed to [digits!] - in a const context,
+/// where panicking results in a compile-time error.
+#[doc(hidden)]
+pub const fn assert_digit_literals(digits: &[u8]) {
+    assert!(
+        crate::validate_digits(digits),
+        "digits! only accepts 0-9 digits"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DigitSequence;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Creating a sequence from literals" {
+            it "should accept every digit, with a trailing comma" {
+                eq!(digits![0, 1, 2, 3, 4, 5, 6, 7, 8, 9,], DigitSequence::try_from(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap());
+            }
+
+            it "should accept constant expressions" {
+                const NINE: u8 = 9;
+
+                eq!(digits![NINE, NINE - 1], [9, 8]);
+            }
+        }
+    }
+}
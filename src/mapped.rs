@@ -0,0 +1,233 @@
+use crate::strings::ascii_to_digits;
+use crate::{CrateError, CrateResult, DigitSequence};
+use core::iter::FusedIterator;
+use memmap2::Mmap;
+use std::fs::File;
+use std::path::Path;
+
+/// File of ASCII digits - such as the digits of π - mapped into memory,
+/// so that even gigabytes of digits can be analyzed without loading
+/// them into RAM: the operating system pages them in on demand.
+///
+/// Validation is performed on demand, too: [digits](MappedDigitFile::digits)
+/// yields a [CrateError::NonDigitByte] upon the first non-digit byte; trailing
+/// ASCII whitespace - such as the final newline - is ignored.
+///
+/// **REQUIRES FEATURE**: `mmap`.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let path = std::env::temp_dir().join("digit-sequence-mapped-doc.txt");
+/// std::fs::write(&path, "31415926535\n")?;
+///
+/// let file = MappedDigitFile::open(&path)?;
+/// assert_eq!(file.len(), 11);
+///
+/// let threes = file.digits().filter(|digit| *digit == Ok(3)).count();
+/// assert_eq!(threes, 2);
+///
+/// assert_eq!(file.get(2..6).unwrap()?, [4, 1, 5, 9]);
+/// assert_eq!(file.to_digit_sequence()?.to_string(), "31415926535");
+///
+/// std::fs::remove_file(&path)?;
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MappedDigitFile {
+    mmap: Option<Mmap>,
+    len: usize,
+}
+
+impl MappedDigitFile {
+    /// Maps the file at the given path into memory.
+    ///
+    /// As usual with memory-mapped files, the content must not be modified
+    /// by other processes while it is mapped.
+    pub fn open(path: impl AsRef<Path>) -> std::io::Result<MappedDigitFile> {
+        let file = File::open(path)?;
+
+        if file.metadata()?.len() == 0 {
+            return Ok(MappedDigitFile { mmap: None, len: 0 });
+        }
+
+        // SAFETY: the mapping is read-only, and the documentation requires
+        // the file not to be modified while mapped.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let len = mmap.trim_ascii_end().len();
+
+        Ok(MappedDigitFile {
+            mmap: Some(mmap),
+            len,
+        })
+    }
+
+    /// Number of bytes - that is, of digits, if the file is valid -
+    /// excluding the trailing whitespace.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Tells whether the file contains no digits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The raw ASCII bytes, excluding the trailing whitespace.
+    pub fn as_bytes(&self) -> &[u8] {
+        match &self.mmap {
+            Some(mmap) => &mmap[..self.len],
+            None => &[],
+        }
+    }
+
+    /// Iterates over the digits, validating each byte just before yielding it;
+    /// the iteration ends after the first [CrateError::NonDigitByte].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let path = std::env::temp_dir().join("digit-sequence-mapped-digits-doc.txt");
+    /// std::fs::write(&path, "90x7")?;
+    ///
+    /// let file = MappedDigitFile::open(&path)?;
+    /// let digits: Vec<CrateResult<u8>> = file.digits().collect();
+    ///
+    /// assert_eq!(digits, [Ok(9), Ok(0), Err(CrateError::NonDigitByte(b'x'))]);
+    ///
+    /// std::fs::remove_file(&path)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn digits(&self) -> MappedDigits<'_> {
+        MappedDigits {
+            bytes: self.as_bytes().iter(),
+            failed: false,
+        }
+    }
+
+    /// Scans the whole file, returning the first [CrateError::NonDigitByte], if any.
+    pub fn validate(&self) -> CrateResult<()> {
+        self.digits().try_for_each(|digit| digit.map(|_| ()))
+    }
+
+    /// Loads the digits within the given byte range into a [DigitSequence] -
+    /// or returns [None] if the range is out of bounds.
+    pub fn get(&self, range: core::ops::Range<usize>) -> Option<CrateResult<DigitSequence>> {
+        self.as_bytes().get(range).map(parse_ascii)
+    }
+
+    /// Loads all the digits into a [DigitSequence].
+    pub fn to_digit_sequence(&self) -> CrateResult<DigitSequence> {
+        parse_ascii(self.as_bytes())
+    }
+}
+
+/// Just like [DigitSequence::from_ascii], but reporting the raw invalid byte -
+/// since the file content is not necessarily UTF-8.
+fn parse_ascii(bytes: &[u8]) -> CrateResult<DigitSequence> {
+    ascii_to_digits(bytes)
+        .map(DigitSequence)
+        .map_err(|invalid_index| CrateError::NonDigitByte(bytes[invalid_index]))
+}
+
+/// Iterator over the digits of a [MappedDigitFile], returned by
+/// [MappedDigitFile::digits].
+#[derive(Debug, Clone)]
+pub struct MappedDigits<'a> {
+    bytes: core::slice::Iter<'a, u8>,
+    failed: bool,
+}
+
+impl Iterator for MappedDigits<'_> {
+    type Item = CrateResult<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let byte = *self.bytes.next()?;
+
+        if byte.is_ascii_digit() {
+            Some(Ok(byte - b'0'))
+        } else {
+            self.failed = true;
+            Some(Err(CrateError::NonDigitByte(byte)))
+        }
+    }
+}
+
+impl FusedIterator for MappedDigits<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+    use std::path::PathBuf;
+
+    fn write_temp_file(name: &str, content: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("digit-sequence-mapped-{}", name));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    speculate! {
+        describe "Mapped digit file" {
+            it "should support empty files" {
+                let path = write_temp_file("empty.txt", b"");
+                let file = MappedDigitFile::open(&path).unwrap();
+
+                assert!(file.is_empty());
+                eq!(file.to_digit_sequence().unwrap(), []);
+                eq!(file.validate(), Ok(()));
+
+                std::fs::remove_file(&path).unwrap();
+            }
+
+            it "should ignore trailing whitespace only" {
+                let path = write_temp_file("whitespace.txt", b"0392 \r\n");
+                let file = MappedDigitFile::open(&path).unwrap();
+
+                eq!(file.to_digit_sequence().unwrap(), [0, 3, 9, 2]);
+                eq!(file.validate(), Ok(()));
+
+                std::fs::remove_file(&path).unwrap();
+            }
+
+            it "should report the first invalid byte" {
+                let path = write_temp_file("invalid.txt", b"03 92");
+                let file = MappedDigitFile::open(&path).unwrap();
+
+                eq!(file.validate(), Err(CrateError::NonDigitByte(b' ')));
+                eq!(file.get(0..2).unwrap().unwrap(), [0, 3]);
+                eq!(file.get(4..9), None);
+
+                std::fs::remove_file(&path).unwrap();
+            }
+
+            it "should report non-ASCII bytes as they are" {
+                let path = write_temp_file("non-ascii.txt", "03€".as_bytes());
+                let file = MappedDigitFile::open(&path).unwrap();
+
+                eq!(file.validate(), Err(CrateError::NonDigitByte(0xE2)));
+                eq!(file.to_digit_sequence(), Err(CrateError::NonDigitByte(0xE2)));
+
+                std::fs::remove_file(&path).unwrap();
+            }
+
+            it "should fail for missing files" {
+                let path = std::env::temp_dir().join("digit-sequence-mapped-missing.txt");
+
+                assert!(MappedDigitFile::open(&path).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{CrateError, CrateResult, DigitSequence};
+use core::fmt::Display;
+use core::num::NonZeroUsize;
+use core::str::FromStr;
+
+/// [DigitSequence] guaranteed to contain at least one digit - for domains
+/// such as card numbers and OTPs, where an empty sequence is never valid:
+/// the check is performed once, at creation, so accessors such as
+/// [first](NonEmptyDigitSequence::first) are infallible.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let otp: NonEmptyDigitSequence = "902134".parse()?;
+///
+/// assert_eq!(otp.first(), 9);
+/// assert_eq!(otp.last(), 4);
+/// assert_eq!(otp.len().get(), 6);
+///
+/// let sequence: DigitSequence = otp.into();
+/// assert_eq!(sequence, [9, 0, 2, 1, 3, 4]);
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The empty sequence results in a [CrateError::EmptySequence]:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let result: CrateResult<NonEmptyDigitSequence> = DigitSequence::new().try_into();
+/// assert_eq!(result, Err(CrateError::EmptySequence));
+///
+/// let result: CrateResult<NonEmptyDigitSequence> = "".parse();
+/// assert_eq!(result, Err(CrateError::EmptySequence));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyDigitSequence(DigitSequence);
+
+impl NonEmptyDigitSequence {
+    /// The first digit.
+    pub fn first(&self) -> u8 {
+        self.0 .0[0]
+    }
+
+    /// The last digit.
+    pub fn last(&self) -> u8 {
+        self.0 .0[self.0 .0.len() - 1]
+    }
+
+    /// Number of digits - which is never 0.
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.0 .0.len()).expect("The sequence is never empty")
+    }
+
+    /// The digits, as a [DigitSequence].
+    pub fn as_sequence(&self) -> &DigitSequence {
+        &self.0
+    }
+}
+
+impl TryFrom<DigitSequence> for NonEmptyDigitSequence {
+    type Error = CrateError;
+
+    fn try_from(sequence: DigitSequence) -> CrateResult<Self> {
+        if sequence.is_empty() {
+            Err(CrateError::EmptySequence)
+        } else {
+            Ok(NonEmptyDigitSequence(sequence))
+        }
+    }
+}
+
+impl TryFrom<&DigitSequence> for NonEmptyDigitSequence {
+    type Error = CrateError;
+
+    fn try_from(sequence: &DigitSequence) -> CrateResult<Self> {
+        sequence.clone().try_into()
+    }
+}
+
+impl From<NonEmptyDigitSequence> for DigitSequence {
+    fn from(sequence: NonEmptyDigitSequence) -> DigitSequence {
+        sequence.0
+    }
+}
+
+impl AsRef<DigitSequence> for NonEmptyDigitSequence {
+    fn as_ref(&self) -> &DigitSequence {
+        &self.0
+    }
+}
+
+/// Parsing follows the rules of [DigitSequence] - but the empty
+/// string results in a [CrateError::EmptySequence].
+impl FromStr for NonEmptyDigitSequence {
+    type Err = CrateError;
+
+    fn from_str(s: &str) -> CrateResult<Self> {
+        s.parse::<DigitSequence>()?.try_into()
+    }
+}
+
+impl Display for NonEmptyDigitSequence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for NonEmptyDigitSequence {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<DigitSequence> for NonEmptyDigitSequence {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Non-empty sequences" {
+            it "should access the bounds of a single digit" {
+                let sequence: NonEmptyDigitSequence = "7".parse().unwrap();
+
+                eq!((sequence.first(), sequence.last(), sequence.len().get()), (7, 7, 1));
+            }
+
+            it "should report invalid digits before emptiness" {
+                let result: CrateResult<NonEmptyDigitSequence> = "x".parse();
+
+                eq!(result, Err(CrateError::NonDigitChar('x')));
+            }
+        }
+    }
+}
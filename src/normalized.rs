@@ -0,0 +1,168 @@
+use crate::arithmetic::{compare, normalize};
+use crate::{CrateError, CrateResult, DigitSequence};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::str::FromStr;
+
+impl DigitSequence {
+    /// Strips the leading zeros of the sequence - see [NormalizedDigitSequence].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = "000387".parse()?;
+    /// assert_eq!(sequence.normalized(), [3, 8, 7]);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn normalized(self) -> NormalizedDigitSequence {
+        NormalizedDigitSequence(DigitSequence(normalize(self.0)))
+    }
+}
+
+/// [DigitSequence] guaranteed to have no leading zeros - with 0 represented
+/// by the single digit 0 - so that each number has exactly one canonical
+/// representation: ideal for numeric keys.
+///
+/// It can be created from any [DigitSequence], infallibly - and,
+/// since it is canonical, its [Ord] is also numeric:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let key: NormalizedDigitSequence = "00042".parse()?;
+/// assert_eq!(key, [4, 2]);
+/// assert_eq!(key.to_string(), "42");
+///
+/// let zero: NormalizedDigitSequence = DigitSequence::new().into();
+/// assert_eq!(zero, [0]);
+///
+/// let nine: NormalizedDigitSequence = "09".parse()?;
+/// let ten: NormalizedDigitSequence = "10".parse()?;
+/// assert!(nine < ten);
+///
+/// let sequence: DigitSequence = ten.into();
+/// assert_eq!(sequence, [1, 0]);
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NormalizedDigitSequence(DigitSequence);
+
+impl NormalizedDigitSequence {
+    /// The normalized digits, as a [DigitSequence].
+    pub fn as_sequence(&self) -> &DigitSequence {
+        &self.0
+    }
+}
+
+/// The default value is 0.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// assert_eq!(NormalizedDigitSequence::default(), [0]);
+/// ```
+impl Default for NormalizedDigitSequence {
+    fn default() -> Self {
+        DigitSequence::new().normalized()
+    }
+}
+
+impl From<DigitSequence> for NormalizedDigitSequence {
+    fn from(sequence: DigitSequence) -> NormalizedDigitSequence {
+        sequence.normalized()
+    }
+}
+
+impl From<&DigitSequence> for NormalizedDigitSequence {
+    fn from(sequence: &DigitSequence) -> NormalizedDigitSequence {
+        sequence.clone().normalized()
+    }
+}
+
+impl From<NormalizedDigitSequence> for DigitSequence {
+    fn from(sequence: NormalizedDigitSequence) -> DigitSequence {
+        sequence.0
+    }
+}
+
+impl AsRef<DigitSequence> for NormalizedDigitSequence {
+    fn as_ref(&self) -> &DigitSequence {
+        &self.0
+    }
+}
+
+/// Parsing follows the rules of [DigitSequence], then normalizes the digits.
+impl FromStr for NormalizedDigitSequence {
+    type Err = CrateError;
+
+    fn from_str(s: &str) -> CrateResult<Self> {
+        s.parse::<DigitSequence>().map(DigitSequence::normalized)
+    }
+}
+
+impl PartialOrd for NormalizedDigitSequence {
+    fn partial_cmp(&self, other: &NormalizedDigitSequence) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NormalizedDigitSequence {
+    fn cmp(&self, other: &NormalizedDigitSequence) -> Ordering {
+        compare(&self.0 .0, &other.0 .0)
+    }
+}
+
+impl Display for NormalizedDigitSequence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for NormalizedDigitSequence {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<DigitSequence> for NormalizedDigitSequence {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Normalizing a sequence" {
+            it "should strip the leading zeros" {
+                for (source, expected) in [("", "0"), ("0", "0"), ("0000", "0"), ("0100", "100"), ("7", "7")] {
+                    let normalized: NormalizedDigitSequence = source.parse().unwrap();
+
+                    eq!(normalized.to_string(), expected);
+                }
+            }
+
+            it "should sort numerically" {
+                let mut keys: Vec<NormalizedDigitSequence> = ["100", "9", "010", "0"]
+                    .into_iter()
+                    .map(|source| source.parse().unwrap())
+                    .collect();
+
+                keys.sort();
+
+                let sorted: Vec<String> = keys.iter().map(|key| key.to_string()).collect();
+                eq!(sorted, ["0", "9", "10", "100"]);
+            }
+        }
+    }
+}
@@ -0,0 +1,432 @@
+use crate::arithmetic::{add, mul_small, normalize, significant_digits};
+use crate::{CrateError, CrateResult, DigitSequence, DigitSlice, Digits};
+
+impl DigitSequence {
+    /// Creates a *repdigit* - a sequence of the given length
+    /// where the given digit is repeated.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence = DigitSequence::repdigit(7, 4)?;
+    /// assert_eq!(sequence, [7, 7, 7, 7]);
+    ///
+    /// let sequence = DigitSequence::repdigit(7, 0)?;
+    /// assert_eq!(sequence, []);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// If the digit is out of range, the result is a [CrateError::NonDigitNumber]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let result = DigitSequence::repdigit(10, 4);
+    /// assert_eq!(result, Err(CrateError::NonDigitNumber(10)));
+    /// ```
+    pub fn repdigit(digit: u8, len: usize) -> CrateResult<DigitSequence> {
+        if digit >= 10 {
+            return Err(CrateError::NonDigitNumber(digit as u128));
+        }
+
+        Ok(DigitSequence(core::iter::repeat_n(digit, len).collect()))
+    }
+
+    /// Creates a *repunit* - a sequence of the given length only made of 1.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// assert_eq!(DigitSequence::repunit(3), [1, 1, 1]);
+    /// assert_eq!(DigitSequence::repunit(0), []);
+    /// ```
+    pub fn repunit(len: usize) -> DigitSequence {
+        DigitSequence(core::iter::repeat_n(1, len).collect())
+    }
+
+    /// Tells whether the sequence is a *repdigit* - that is,
+    /// a non-empty sequence whose digits are all equal.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let repdigit: DigitSequence = 5555u16.into();
+    /// assert!(repdigit.is_repdigit());
+    ///
+    /// let single_digit: DigitSequence = 5u8.into();
+    /// assert!(single_digit.is_repdigit());
+    ///
+    /// let non_repdigit: DigitSequence = 5545u16.into();
+    /// assert!(!non_repdigit.is_repdigit());
+    ///
+    /// assert!(!DigitSequence::new().is_repdigit());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_repdigit(&self) -> bool {
+        self.as_digit_slice().is_repdigit()
+    }
+
+    /// Tells whether the sequence is a *repunit* - that is,
+    /// a non-empty sequence only made of 1.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let repunit: DigitSequence = 1111u16.into();
+    /// assert!(repunit.is_repunit());
+    ///
+    /// let non_repunit: DigitSequence = 2222u16.into();
+    /// assert!(!non_repunit.is_repunit());
+    ///
+    /// assert!(!DigitSequence::new().is_repunit());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_repunit(&self) -> bool {
+        self.as_digit_slice().is_repunit()
+    }
+
+    /// Tells whether the represented value is divisible by the given divisor,
+    /// without converting the sequence to an integer - so sequences of any
+    /// length are supported.
+    ///
+    /// The classical digit-based rules are applied for 2, 3, 4, 5, 6, 8, 9, 10 and 11;
+    /// any other divisor is handled via [remainder](DigitSequence::remainder).
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 1848u16.into();
+    ///
+    /// assert!(sequence.is_divisible_by(8));
+    /// assert!(sequence.is_divisible_by(11));
+    /// assert!(sequence.is_divisible_by(7));
+    /// assert!(!sequence.is_divisible_by(9));
+    ///
+    /// let huge: DigitSequence = "9".repeat(500).parse()?;
+    /// assert!(huge.is_divisible_by(9));
+    /// assert!(huge.is_divisible_by(11));
+    /// assert!(!huge.is_divisible_by(2));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Just like the `%` operator on integers, this method panics if `divisor` is 0.
+    pub fn is_divisible_by(&self, divisor: u8) -> bool {
+        self.as_digit_slice().is_divisible_by(divisor)
+    }
+
+    /// Tells whether the represented value is a *Harshad* (or *Niven*) number -
+    /// that is, a positive value divisible by the sum of its digits.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let harshad: DigitSequence = 1729u16.into();
+    /// assert!(harshad.is_harshad());
+    ///
+    /// let non_harshad: DigitSequence = 11u8.into();
+    /// assert!(!non_harshad.is_harshad());
+    ///
+    /// let huge_harshad: DigitSequence = format!("1{}", "0".repeat(100)).parse()?;
+    /// assert!(huge_harshad.is_harshad());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Since 0 is not positive, sequences only made of zeros - as well
+    /// as the empty sequence - are *not* Harshad numbers:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert!(!DigitSequence::new().is_harshad());
+    ///
+    /// let zeros: DigitSequence = [0, 0].try_into()?;
+    /// assert!(!zeros.is_harshad());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_harshad(&self) -> bool {
+        self.as_digit_slice().is_harshad()
+    }
+
+    /// Tells whether the represented value is an *Armstrong* (or *narcissistic*)
+    /// number - that is, whether it equals the sum of its digits, each raised
+    /// to the power of the sequence length.
+    ///
+    /// The computation is performed with arbitrary precision, so it never overflows.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let armstrong: DigitSequence = 153u8.into();
+    /// assert!(armstrong.is_armstrong());
+    ///
+    /// let non_armstrong: DigitSequence = 154u8.into();
+    /// assert!(!non_armstrong.is_armstrong());
+    ///
+    /// let huge_armstrong: DigitSequence = "115132219018763992565095597973971522401".parse()?;
+    /// assert!(huge_armstrong.is_armstrong());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Leading zeros are part of the sequence length, and
+    /// the empty sequence is *not* an Armstrong number:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let padded: DigitSequence = [0, 1, 5, 3].try_into()?;
+    /// assert!(!padded.is_armstrong());
+    ///
+    /// assert!(!DigitSequence::new().is_armstrong());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_armstrong(&self) -> bool {
+        self.as_digit_slice().is_armstrong()
+    }
+
+    /// Tells whether the represented value is *automorphic* - that is,
+    /// whether its square ends with the value itself.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let automorphic: DigitSequence = 376u16.into();
+    /// assert!(automorphic.is_automorphic());
+    ///
+    /// let non_automorphic: DigitSequence = 377u16.into();
+    /// assert!(!non_automorphic.is_automorphic());
+    ///
+    /// let huge_automorphic: DigitSequence = "7109376".parse()?;
+    /// assert!(huge_automorphic.is_automorphic());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The check is performed on the numeric value, so leading zeros
+    /// are ignored; the empty sequence, however, is *not* automorphic:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let padded: DigitSequence = [0, 0, 2, 5].try_into()?;
+    /// assert!(padded.is_automorphic());
+    ///
+    /// assert!(!DigitSequence::new().is_automorphic());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_automorphic(&self) -> bool {
+        self.as_digit_slice().is_automorphic()
+    }
+}
+
+impl DigitSlice<'_> {
+    /// Just like [DigitSequence::is_repdigit].
+    pub fn is_repdigit(&self) -> bool {
+        match self.0.first() {
+            Some(first) => self.0.iter().all(|digit| digit == first),
+            None => false,
+        }
+    }
+
+    /// Just like [DigitSequence::is_repunit].
+    pub fn is_repunit(&self) -> bool {
+        !self.is_empty() && self.0.iter().all(|&digit| digit == 1)
+    }
+
+    /// Just like [DigitSequence::is_divisible_by].
+    pub fn is_divisible_by(&self, divisor: u8) -> bool {
+        let last_digits_value = |count: usize| {
+            let start = self.0.len().saturating_sub(count);
+
+            self.0[start..]
+                .iter()
+                .fold(0u16, |value, &digit| value * 10 + digit as u16)
+        };
+
+        match divisor {
+            0 => panic!("Division by zero"),
+            1 => true,
+            2 => last_digits_value(1).is_multiple_of(2),
+            3 => self.digit_sum().is_multiple_of(3),
+            4 => last_digits_value(2).is_multiple_of(4),
+            5 => last_digits_value(1).is_multiple_of(5),
+            6 => self.is_divisible_by(2) && self.is_divisible_by(3),
+            8 => last_digits_value(3).is_multiple_of(8),
+            9 => self.digit_sum().is_multiple_of(9),
+            10 => last_digits_value(1) == 0,
+            11 => {
+                let alternating_sum =
+                    self.0
+                        .iter()
+                        .rev()
+                        .enumerate()
+                        .fold(0i64, |sum, (index, &digit)| match index % 2 {
+                            0 => sum + digit as i64,
+                            _ => sum - digit as i64,
+                        });
+
+                alternating_sum % 11 == 0
+            }
+            _ => self.remainder(divisor as u64) == 0,
+        }
+    }
+
+    /// Just like [DigitSequence::is_harshad].
+    pub fn is_harshad(&self) -> bool {
+        match self.digit_sum() {
+            0 => false,
+            digit_sum => self.remainder(digit_sum) == 0,
+        }
+    }
+
+    /// Just like [DigitSequence::is_armstrong].
+    pub fn is_armstrong(&self) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let exponent = self.0.len();
+        let mut digit_counts = [0u64; 10];
+
+        for &digit in self.0 {
+            digit_counts[digit as usize] += 1;
+        }
+
+        let mut total = Digits::from(&[0][..]);
+
+        for (digit, &count) in digit_counts.iter().enumerate().skip(1) {
+            if count == 0 {
+                continue;
+            }
+
+            let mut power = Digits::from(&[1][..]);
+
+            for _ in 0..exponent {
+                power = mul_small(&power, digit as u64);
+            }
+
+            total = add(&total, &mul_small(&power, count));
+        }
+
+        significant_digits(&total) == significant_digits(self.0)
+    }
+
+    /// Just like [DigitSequence::is_automorphic].
+    pub fn is_automorphic(&self) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let value = DigitSequence(normalize(self.0.into()));
+        let square = &value * &value;
+
+        square.ends_with(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Checking for Harshad numbers" {
+            it "should match the values below 50" {
+                let expected: Vec<u8> = vec![
+                    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12, 18, 20, 21, 24, 27, 30, 36, 40, 42, 45, 48
+                ];
+
+                let actual: Vec<u8> = (0u8..50)
+                    .filter(|&value| DigitSequence::from(value).is_harshad())
+                    .collect();
+
+                eq!(actual, expected);
+            }
+
+            it "should ignore leading zeros" {
+                let sequence: DigitSequence = "00018".parse().unwrap();
+
+                assert!(sequence.is_harshad());
+            }
+        }
+
+        describe "Checking divisibility" {
+            it "should match integer divisibility" {
+                for value in (0u16..2000).chain([u16::MAX - 1, u16::MAX]) {
+                    let sequence = DigitSequence::from(value);
+
+                    for divisor in 1u8..=30 {
+                        eq!(
+                            sequence.is_divisible_by(divisor),
+                            value % divisor as u16 == 0,
+                            "{} / {}",
+                            value,
+                            divisor
+                        );
+                    }
+                }
+            }
+
+            it "should consider the empty sequence as 0" {
+                assert!(DigitSequence::new().is_divisible_by(7));
+            }
+        }
+
+        describe "Checking for Armstrong numbers" {
+            it "should match the values below 10000" {
+                let expected: Vec<u16> = vec![
+                    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 153, 370, 371, 407, 1634, 8208, 9474
+                ];
+
+                let actual: Vec<u16> = (0u16..10000)
+                    .filter(|&value| DigitSequence::from(value).is_armstrong())
+                    .collect();
+
+                eq!(actual, expected);
+            }
+        }
+
+        describe "Checking for automorphic numbers" {
+            it "should match the values below 10000" {
+                let expected: Vec<u16> = vec![0, 1, 5, 6, 25, 76, 376, 625, 9376];
+
+                let actual: Vec<u16> = (0u16..10000)
+                    .filter(|&value| DigitSequence::from(value).is_automorphic())
+                    .collect();
+
+                eq!(actual, expected);
+            }
+        }
+    }
+}
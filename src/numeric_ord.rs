@@ -0,0 +1,136 @@
+use crate::arithmetic::{compare, significant_digits};
+use crate::DigitSequence;
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::hash::{Hash, Hasher};
+
+impl DigitSequence {
+    /// Wraps the sequence so that it is compared by numeric value - see [NumericOrd].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let padded: DigitSequence = "0100".parse()?;
+    /// let short: DigitSequence = "99".parse()?;
+    ///
+    /// assert!(padded < short);
+    /// assert!(padded.by_value() > short.by_value());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn by_value(self) -> NumericOrd {
+        NumericOrd(self)
+    }
+}
+
+/// Wrapper around a [DigitSequence] whose equality, ordering and hashing
+/// rely on its numeric value: leading zeros are ignored - so the empty
+/// sequence equals 0 - and, once they are stripped, longer sequences
+/// come after shorter ones.
+///
+/// Consequently, maps and sets keyed by zero-padded inputs sort
+/// the way a human would expect - whereas the derived [Ord] of
+/// [DigitSequence] compares the digits lexicographically:
+///
+/// ```
+/// use digit_sequence::*;
+/// use std::collections::BTreeSet;
+///
+/// # fn main() -> GenericResult<()> {
+/// let codes: BTreeSet<NumericOrd> = ["10", "9", "007", "100"]
+///     .into_iter()
+///     .map(|code| Ok(code.parse::<DigitSequence>()?.by_value()))
+///     .collect::<CrateResult<_>>()?;
+///
+/// let sorted: Vec<String> = codes.iter().map(|code| code.to_string()).collect();
+/// assert_eq!(sorted, ["007", "9", "10", "100"]);
+///
+/// assert!(codes.contains(&"0010".parse::<DigitSequence>()?.by_value()));
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The original digits - including any leading zeros - remain
+/// available via the public field.
+#[derive(Debug, Clone, Default)]
+pub struct NumericOrd(pub DigitSequence);
+
+impl From<DigitSequence> for NumericOrd {
+    fn from(sequence: DigitSequence) -> NumericOrd {
+        NumericOrd(sequence)
+    }
+}
+
+impl From<NumericOrd> for DigitSequence {
+    fn from(wrapper: NumericOrd) -> DigitSequence {
+        wrapper.0
+    }
+}
+
+impl PartialEq for NumericOrd {
+    fn eq(&self, other: &NumericOrd) -> bool {
+        significant_digits(&self.0 .0) == significant_digits(&other.0 .0)
+    }
+}
+
+impl Eq for NumericOrd {}
+
+impl PartialOrd for NumericOrd {
+    fn partial_cmp(&self, other: &NumericOrd) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NumericOrd {
+    fn cmp(&self, other: &NumericOrd) -> Ordering {
+        compare(&self.0 .0, &other.0 .0)
+    }
+}
+
+impl Hash for NumericOrd {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        significant_digits(&self.0 .0).hash(state)
+    }
+}
+
+impl Display for NumericOrd {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+    use std::collections::HashSet;
+
+    speculate! {
+        describe "Numeric ordering" {
+            fn by_value(source: &str) -> NumericOrd {
+                source.parse::<DigitSequence>().unwrap().by_value()
+            }
+
+            it "should consider zero-padded values equal" {
+                eq!(by_value("0042"), by_value("42"));
+                eq!(by_value(""), by_value("000"));
+            }
+
+            it "should hash consistently with equality" {
+                let values: HashSet<NumericOrd> = ["0042", "42", "042", "420"].into_iter().map(by_value).collect();
+
+                eq!(values.len(), 2);
+            }
+
+            it "should compare the lengths first" {
+                assert!(by_value("0999") < by_value("1000"));
+                assert!(by_value("1000") > by_value("0000999"));
+                assert!(by_value("12") < by_value("21"));
+            }
+        }
+    }
+}
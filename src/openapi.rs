@@ -0,0 +1,47 @@
+use crate::DigitSequence;
+use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Schema, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+/// With the `utoipa` feature, [DigitSequence] is described in OpenAPI documents
+/// as an array of integers from 0 to 9.
+///
+/// **REQUIRES FEATURE**: `utoipa`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use utoipa::{PartialSchema, ToSchema};
+///
+/// # fn main() -> GenericResult<()> {
+/// let schema = serde_json::to_value(DigitSequence::schema())?;
+///
+/// assert_eq!(DigitSequence::name(), "DigitSequence");
+/// assert_eq!(schema["type"], "array");
+/// assert_eq!(schema["items"]["minimum"], 0);
+/// assert_eq!(schema["items"]["maximum"], 9);
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Fields serialized via [serde_str](crate::serde_str) should rather refer to
+/// its dedicated [schema](crate::serde_str::openapi_schema), describing a digit string.
+impl PartialSchema for DigitSequence {
+    fn schema() -> RefOr<Schema> {
+        ArrayBuilder::new()
+            .description(Some("Sequence of 0-9 digits"))
+            .items(
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .minimum(Some(0))
+                    .maximum(Some(9)),
+            )
+            .into()
+    }
+}
+
+impl ToSchema for DigitSequence {
+    fn name() -> alloc::borrow::Cow<'static, str> {
+        "DigitSequence".into()
+    }
+}
@@ -0,0 +1,185 @@
+//! One-time password generation, according to
+//! [RFC 4226](https://www.rfc-editor.org/rfc/rfc4226) (HOTP) and
+//! [RFC 6238](https://www.rfc-editor.org/rfc/rfc6238) (TOTP).
+//!
+//! **REQUIRES FEATURE**: `otp`.
+//!
+//! Passwords are returned as [DigitSequence] values having exactly
+//! the requested length - so leading zeros are preserved.
+
+use crate::{DigitSequence, Digits};
+use alloc::vec::Vec;
+use hmac::{Hmac, Mac};
+
+/// The hash function underlying the HMAC computation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OtpAlgorithm {
+    /// HMAC-SHA-1 - the default in both RFC 4226 and RFC 6238.
+    #[default]
+    Sha1,
+
+    /// HMAC-SHA-256.
+    Sha256,
+
+    /// HMAC-SHA-512.
+    Sha512,
+}
+
+/// The number of digits in a one-time password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OtpLength {
+    /// 6 digits - the most common choice.
+    #[default]
+    Six,
+
+    /// 8 digits.
+    Eight,
+}
+
+impl OtpLength {
+    /// The number of digits.
+    ///
+    /// ```
+    /// use digit_sequence::otp::OtpLength;
+    ///
+    /// assert_eq!(OtpLength::Six.len(), 6);
+    /// assert_eq!(OtpLength::Eight.len(), 8);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Six => 6,
+            Self::Eight => 8,
+        }
+    }
+}
+
+fn hmac_digest<M: Mac + hmac::digest::KeyInit>(secret: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = <M as Mac>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Computes the HOTP value for the given secret and counter.
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::otp::*;
+///
+/// let secret = b"12345678901234567890";
+///
+/// let password = hotp(OtpAlgorithm::Sha1, secret, 1, OtpLength::Six);
+/// assert_eq!(password.to_string(), "287082");
+///
+/// let password = hotp(OtpAlgorithm::Sha1, secret, 7, OtpLength::Six);
+/// assert_eq!(password.to_string(), "162583");
+/// ```
+pub fn hotp(
+    algorithm: OtpAlgorithm,
+    secret: &[u8],
+    counter: u64,
+    length: OtpLength,
+) -> DigitSequence {
+    let message = counter.to_be_bytes();
+
+    let digest = match algorithm {
+        OtpAlgorithm::Sha1 => hmac_digest::<Hmac<sha1::Sha1>>(secret, &message),
+        OtpAlgorithm::Sha256 => hmac_digest::<Hmac<sha2::Sha256>>(secret, &message),
+        OtpAlgorithm::Sha512 => hmac_digest::<Hmac<sha2::Sha512>>(secret, &message),
+    };
+
+    let offset = (digest.last().unwrap() & 0x0f) as usize;
+
+    let mut code = u32::from_be_bytes(digest[offset..offset + 4].try_into().unwrap()) & 0x7fff_ffff;
+
+    let mut digits: Digits = core::iter::repeat_n(0, length.len()).collect();
+
+    for digit in digits.iter_mut().rev() {
+        *digit = (code % 10) as u8;
+        code /= 10;
+    }
+
+    DigitSequence(digits)
+}
+
+/// Computes the TOTP value for the given secret, at the given Unix time
+/// and with the given time step - usually 30 seconds.
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::otp::*;
+///
+/// let secret = b"12345678901234567890";
+///
+/// let password = totp(OtpAlgorithm::Sha1, secret, 59, 30, OtpLength::Eight);
+/// assert_eq!(password.to_string(), "94287082");
+///
+/// let password = totp(OtpAlgorithm::Sha1, secret, 1111111109, 30, OtpLength::Eight);
+/// assert_eq!(password.to_string(), "07081804");
+/// ```
+///
+/// # Panics
+///
+/// If `step_seconds` is 0.
+pub fn totp(
+    algorithm: OtpAlgorithm,
+    secret: &[u8],
+    unix_seconds: u64,
+    step_seconds: u64,
+    length: OtpLength,
+) -> DigitSequence {
+    hotp(algorithm, secret, unix_seconds / step_seconds, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    const SHA1_SECRET: &[u8] = b"12345678901234567890";
+    const SHA256_SECRET: &[u8] = b"12345678901234567890123456789012";
+    const SHA512_SECRET: &[u8] =
+        b"1234567890123456789012345678901234567890123456789012345678901234";
+
+    speculate! {
+        describe "HOTP" {
+            it "should match the RFC 4226 test vectors" {
+                let expected = [
+                    "755224", "287082", "359152", "969429", "338314",
+                    "254676", "287922", "162583", "399871", "520489",
+                ];
+
+                for (counter, expected_password) in expected.into_iter().enumerate() {
+                    let password = hotp(OtpAlgorithm::Sha1, SHA1_SECRET, counter as u64, OtpLength::Six);
+
+                    eq!(password.to_string(), expected_password);
+                }
+            }
+        }
+
+        describe "TOTP" {
+            fn test_case(unix_seconds: u64, expected_sha1: &str, expected_sha256: &str, expected_sha512: &str) {
+                let password = |algorithm, secret| {
+                    totp(algorithm, secret, unix_seconds, 30, OtpLength::Eight).to_string()
+                };
+
+                eq!(password(OtpAlgorithm::Sha1, SHA1_SECRET), expected_sha1);
+                eq!(password(OtpAlgorithm::Sha256, SHA256_SECRET), expected_sha256);
+                eq!(password(OtpAlgorithm::Sha512, SHA512_SECRET), expected_sha512);
+            }
+
+            it "should match the RFC 6238 test vectors at 59" {
+                test_case(59, "94287082", "46119246", "90693936");
+            }
+
+            it "should match the RFC 6238 test vectors at 1111111109" {
+                test_case(1111111109, "07081804", "68084774", "25091201");
+            }
+
+            it "should match the RFC 6238 test vectors at 20000000000" {
+                test_case(20000000000, "65353130", "77737706", "47863826");
+            }
+        }
+    }
+}
@@ -0,0 +1,491 @@
+use crate::packing::PADDING_NIBBLE;
+use crate::{CrateError, CrateResult, DigitSequence};
+use alloc::{string::String, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+/// Sequence of digits stored two per byte - as packed BCD - thus halving
+/// the memory required by [DigitSequence]: ideal for sequences having
+/// millions of digits.
+///
+/// The bytes have the very same layout returned by [DigitSequence::pack].
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let pi: PackedDigitSequence = "31415".parse()?;
+///
+/// assert_eq!(pi.len(), 5);
+/// assert_eq!(pi.as_packed_bytes(), &[0x31, 0x41, 0x5F]);
+/// assert_eq!(pi.get(2), Some(4));
+/// assert_eq!(pi.to_string(), "31415");
+///
+/// let digits: Vec<u8> = pi.iter().collect();
+/// assert_eq!(digits, [3, 1, 4, 1, 5]);
+///
+/// let sequence: DigitSequence = pi.clone().into();
+/// assert_eq!(sequence, [3, 1, 4, 1, 5]);
+/// assert_eq!(PackedDigitSequence::from(&sequence), pi);
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Ordering is the same as [DigitSequence] - that is, lexicographic:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let short: PackedDigitSequence = "9".parse()?;
+/// let long: PackedDigitSequence = "90".parse()?;
+///
+/// assert!(short < long);
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PackedDigitSequence(Vec<u8>);
+
+impl PackedDigitSequence {
+    /// Creates an empty sequence.
+    pub fn new() -> PackedDigitSequence {
+        PackedDigitSequence(Vec::new())
+    }
+
+    /// Number of digits in the sequence.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(PackedDigitSequence::new().len(), 0);
+    /// assert_eq!("90".parse::<PackedDigitSequence>()?.len(), 2);
+    /// assert_eq!("907".parse::<PackedDigitSequence>()?.len(), 3);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn len(&self) -> usize {
+        match self.0.last() {
+            Some(&byte) if byte & 0x0F == PADDING_NIBBLE => self.0.len() * 2 - 1,
+            _ => self.0.len() * 2,
+        }
+    }
+
+    /// Tells whether the sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The digit at the given index, if any.
+    pub fn get(&self, index: usize) -> Option<u8> {
+        if index < self.len() {
+            Some(nibble(self.0[index / 2], index))
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the digits - unpacking them on the fly.
+    pub fn iter(&self) -> PackedDigits<'_> {
+        PackedDigits {
+            bytes: &self.0,
+            front: 0,
+            back: self.len(),
+        }
+    }
+
+    /// The packed bytes - with the layout described by [DigitSequence::pack].
+    pub fn as_packed_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Creates a sequence from packed bytes - with the layout described by
+    /// [DigitSequence::pack] - without copying them.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence = PackedDigitSequence::from_packed_bytes(vec![0x12, 0x3F])?;
+    /// assert_eq!(sequence, [1, 2, 3]);
+    ///
+    /// let result = PackedDigitSequence::from_packed_bytes(vec![0x1F, 0x23]);
+    /// assert_eq!(result, Err(CrateError::InvalidEncoding));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_packed_bytes(bytes: Vec<u8>) -> CrateResult<PackedDigitSequence> {
+        for (index, &byte) in bytes.iter().enumerate() {
+            let low = byte & 0x0F;
+
+            let low_is_valid = low < 10 || (low == PADDING_NIBBLE && index == bytes.len() - 1);
+
+            if byte >> 4 >= 10 || !low_is_valid {
+                return Err(CrateError::InvalidEncoding);
+            }
+        }
+
+        Ok(PackedDigitSequence(bytes))
+    }
+
+    fn push(&mut self, digit: u8) {
+        match self.0.last_mut() {
+            Some(last) if *last & 0x0F == PADDING_NIBBLE => *last = (*last & 0xF0) | digit,
+            _ => self.0.push((digit << 4) | PADDING_NIBBLE),
+        }
+    }
+}
+
+fn nibble(byte: u8, index: usize) -> u8 {
+    if index.is_multiple_of(2) {
+        byte >> 4
+    } else {
+        byte & 0x0F
+    }
+}
+
+/// Iterator over the digits of a [PackedDigitSequence].
+#[derive(Debug, Clone)]
+pub struct PackedDigits<'a> {
+    bytes: &'a [u8],
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for PackedDigits<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let digit = nibble(self.bytes[self.front / 2], self.front);
+        self.front += 1;
+
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for PackedDigits<'_> {
+    fn next_back(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(nibble(self.bytes[self.back / 2], self.back))
+    }
+}
+
+impl ExactSizeIterator for PackedDigits<'_> {}
+
+impl FusedIterator for PackedDigits<'_> {}
+
+impl<'a> IntoIterator for &'a PackedDigitSequence {
+    type Item = u8;
+
+    type IntoIter = PackedDigits<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl PartialOrd for PackedDigitSequence {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PackedDigitSequence {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// A slice of numbers can be converted as long as its values
+/// are 0-9 digits - otherwise, the result is a [CrateError::NonDigitNumber].
+impl TryFrom<&[u8]> for PackedDigitSequence {
+    type Error = CrateError;
+
+    fn try_from(digits: &[u8]) -> CrateResult<Self> {
+        let mut result = PackedDigitSequence(Vec::with_capacity(digits.len().div_ceil(2)));
+
+        for &digit in digits {
+            if digit >= 10 {
+                return Err(CrateError::NonDigitNumber(digit as u128));
+            }
+
+            result.push(digit);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Conversion from a &[DigitSequence] is always infallible.
+impl From<&DigitSequence> for PackedDigitSequence {
+    fn from(sequence: &DigitSequence) -> PackedDigitSequence {
+        PackedDigitSequence(sequence.pack())
+    }
+}
+
+/// Conversion from a [DigitSequence] is always infallible.
+impl From<DigitSequence> for PackedDigitSequence {
+    fn from(sequence: DigitSequence) -> PackedDigitSequence {
+        (&sequence).into()
+    }
+}
+
+/// Conversion to [DigitSequence] is always infallible.
+impl From<&PackedDigitSequence> for DigitSequence {
+    fn from(sequence: &PackedDigitSequence) -> DigitSequence {
+        DigitSequence(sequence.iter().collect())
+    }
+}
+
+/// Conversion to [DigitSequence] is always infallible.
+impl From<PackedDigitSequence> for DigitSequence {
+    fn from(sequence: PackedDigitSequence) -> DigitSequence {
+        (&sequence).into()
+    }
+}
+
+/// Parsing packs the ASCII digits directly, without an intermediate [DigitSequence];
+/// any other character results in a [CrateError::NonDigitChar].
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let result: CrateResult<PackedDigitSequence> = "90x".parse();
+/// assert_eq!(result, Err(CrateError::NonDigitChar('x')));
+/// ```
+impl FromStr for PackedDigitSequence {
+    type Err = CrateError;
+
+    fn from_str(s: &str) -> CrateResult<Self> {
+        let mut result = PackedDigitSequence(Vec::with_capacity(s.len().div_ceil(2)));
+
+        for current_char in s.chars() {
+            match current_char {
+                '0'..='9' => result.push(current_char as u8 - b'0'),
+                _ => return Err(CrateError::NonDigitChar(current_char)),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// The string representation is the concatenation of the digits,
+/// supporting width, fill, alignment and zero-padding.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: PackedDigitSequence = "387".parse()?;
+///
+/// assert_eq!(format!("{:>5}", sequence), "  387");
+/// assert_eq!(format!("{:05}", sequence), "00387");
+///
+/// # Ok(())
+/// # }
+/// ```
+impl Display for PackedDigitSequence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let digits: String = self.iter().map(|digit| (b'0' + digit) as char).collect();
+
+        if f.sign_aware_zero_pad() {
+            f.pad_integral(true, "", &digits)
+        } else {
+            f.pad(&digits)
+        }
+    }
+}
+
+/// [PackedDigitSequence] can be compared with an array of [u8].
+impl<const N: usize> PartialEq<[u8; N]> for PackedDigitSequence {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.iter().eq(other.iter().copied())
+    }
+}
+
+/// [PackedDigitSequence] can be compared with a [DigitSequence].
+impl PartialEq<DigitSequence> for PackedDigitSequence {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        self.iter().eq(other.iter().copied())
+    }
+}
+
+/// [DigitSequence] can be compared with a [PackedDigitSequence].
+impl PartialEq<PackedDigitSequence> for DigitSequence {
+    fn eq(&self, other: &PackedDigitSequence) -> bool {
+        other == self
+    }
+}
+
+/// Serialization mirrors [DigitSequence]: human-readable formats get
+/// an array of digits, whereas binary formats get the packed bytes as they are.
+///
+/// **REQUIRES FEATURE**: `serde`.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: PackedDigitSequence = "9786".parse()?;
+///
+/// let json = serde_json::to_string(&sequence)?;
+/// assert_eq!(json, "[9,7,8,6]");
+/// assert_eq!(serde_json::from_str::<PackedDigitSequence>(&json)?, sequence);
+///
+/// let binary = rmp_serde::to_vec(&sequence)?;
+/// assert_eq!(binary, [0xC4, 2, 0x97, 0x86]);
+/// assert_eq!(rmp_serde::from_slice::<DigitSequence>(&binary)?, [9, 7, 8, 6]);
+/// assert_eq!(rmp_serde::from_slice::<PackedDigitSequence>(&binary)?, sequence);
+///
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for PackedDigitSequence {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_seq(self.iter())
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct PackedDigitSequenceVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for PackedDigitSequenceVisitor {
+    type Value = PackedDigitSequence;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(formatter, "a sequence of 0-9 digits")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut result = PackedDigitSequence::new();
+
+        while let Some(digit) = seq.next_element::<u8>()? {
+            if digit >= 10 {
+                return Err(serde::de::Error::custom(alloc::format!(
+                    "non-digit number {} at index {}",
+                    digit,
+                    result.len()
+                )));
+            }
+
+            result.push(digit);
+        }
+
+        Ok(result)
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_byte_buf(value.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        PackedDigitSequence::from_packed_bytes(value).map_err(E::custom)
+    }
+}
+
+/// Deserialization validates each digit - or each packed byte.
+///
+/// **REQUIRES FEATURE**: `serde`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PackedDigitSequence {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_seq(PackedDigitSequenceVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(PackedDigitSequenceVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Packed digit sequence" {
+            it "should behave like DigitSequence, for every length" {
+                for sequence in short_sequences(12) {
+                    let length = sequence.iter().len();
+                    let packed = PackedDigitSequence::from(&sequence);
+
+                    eq!(packed.len(), length);
+                    eq!(packed.to_string(), sequence.to_string());
+                    eq!(packed.as_packed_bytes(), &sequence.pack()[..]);
+                    eq!(packed.iter().rev().collect::<Vec<u8>>(), sequence.iter().rev().copied().collect::<Vec<u8>>());
+                    eq!(packed.get(length), None);
+                    eq!(DigitSequence::from(&packed), sequence);
+                    eq!(sequence.to_string().parse::<PackedDigitSequence>().unwrap(), packed);
+                }
+            }
+
+            it "should reject non-digit numbers" {
+                let result = PackedDigitSequence::try_from(&[3, 12][..]);
+
+                eq!(result, Err(CrateError::NonDigitNumber(12)));
+            }
+
+            it "should order just like DigitSequence" {
+                let values = ["", "0", "00", "09", "1", "10", "9", "90"];
+
+                for left in values {
+                    for right in values {
+                        let left_packed: PackedDigitSequence = left.parse().unwrap();
+                        let right_packed: PackedDigitSequence = right.parse().unwrap();
+
+                        let left_sequence: DigitSequence = left.parse().unwrap();
+                        let right_sequence: DigitSequence = right.parse().unwrap();
+
+                        eq!(left_packed.cmp(&right_packed), left_sequence.cmp(&right_sequence));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{CrateError, CrateResult, DigitSequence, Digits};
+use alloc::{string::String, vec::Vec};
+
+/// Nibble filling the low half of the last byte when the number of digits is odd.
+pub(crate) const PADDING_NIBBLE: u8 = 0xF;
+
+/// Digits used by the hexadecimal representation.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// The URL-safe base64 alphabet (RFC 4648, section 5).
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn base64_value(byte: u8) -> Option<u8> {
+    BASE64_ALPHABET
+        .iter()
+        .position(|&symbol| symbol == byte)
+        .map(|position| position as u8)
+}
+
+impl DigitSequence {
+    /// Packs the digits into bytes, 4 bits each - the first digit in the high nibble;
+    /// when the number of digits is odd, the last low nibble is filled with `0xF`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence: DigitSequence = 12345u16.into();
+    /// assert_eq!(sequence.pack(), vec![0x12, 0x34, 0x5F]);
+    ///
+    /// let sequence: DigitSequence = 9070u16.into();
+    /// assert_eq!(sequence.pack(), vec![0x90, 0x70]);
+    ///
+    /// assert_eq!(DigitSequence::new().pack(), Vec::<u8>::new());
+    /// ```
+    pub fn pack(&self) -> Vec<u8> {
+        self.0
+            .chunks(2)
+            .map(|pair| (pair[0] << 4) | pair.get(1).copied().unwrap_or(PADDING_NIBBLE))
+            .collect()
+    }
+
+    /// Creates a sequence from the bytes returned by [pack](DigitSequence::pack).
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence = DigitSequence::unpack(&[0x12, 0x34, 0x5F])?;
+    /// assert_eq!(sequence, [1, 2, 3, 4, 5]);
+    ///
+    /// let sequence: DigitSequence = "0090".parse()?;
+    /// assert_eq!(DigitSequence::unpack(&sequence.pack())?, sequence);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Nibbles that are not digits - including padding anywhere but at the very end -
+    /// result in a [CrateError::InvalidEncoding]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// assert_eq!(DigitSequence::unpack(&[0x1A]), Err(CrateError::InvalidEncoding));
+    /// assert_eq!(DigitSequence::unpack(&[0x1F, 0x23]), Err(CrateError::InvalidEncoding));
+    /// ```
+    pub fn unpack(bytes: &[u8]) -> CrateResult<DigitSequence> {
+        let mut digits = Digits::with_capacity(bytes.len() * 2);
+
+        for (index, &byte) in bytes.iter().enumerate() {
+            let high = byte >> 4;
+            let low = byte & 0x0F;
+
+            if high >= 10 {
+                return Err(CrateError::InvalidEncoding);
+            }
+
+            digits.push(high);
+
+            match low {
+                0..=9 => digits.push(low),
+                PADDING_NIBBLE if index == bytes.len() - 1 => {}
+                _ => return Err(CrateError::InvalidEncoding),
+            }
+        }
+
+        Ok(DigitSequence(digits))
+    }
+
+    /// Lowercase hexadecimal representation of the [packed](DigitSequence::pack) digits.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence: DigitSequence = 12345u16.into();
+    /// assert_eq!(sequence.to_packed_hex(), "12345f");
+    /// ```
+    pub fn to_packed_hex(&self) -> String {
+        self.pack()
+            .into_iter()
+            .flat_map(|byte| {
+                [
+                    HEX_DIGITS[(byte >> 4) as usize],
+                    HEX_DIGITS[(byte & 0x0F) as usize],
+                ]
+            })
+            .map(char::from)
+            .collect()
+    }
+
+    /// Creates a sequence from the string returned by [to_packed_hex](DigitSequence::to_packed_hex);
+    /// uppercase hexadecimal digits are accepted as well.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(DigitSequence::from_packed_hex("12345f")?, [1, 2, 3, 4, 5]);
+    /// assert_eq!(DigitSequence::from_packed_hex("12345F")?, [1, 2, 3, 4, 5]);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Malformed input results in a [CrateError::InvalidEncoding]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// assert_eq!(DigitSequence::from_packed_hex("123"), Err(CrateError::InvalidEncoding));
+    /// assert_eq!(DigitSequence::from_packed_hex("12x4"), Err(CrateError::InvalidEncoding));
+    /// ```
+    pub fn from_packed_hex(hex: &str) -> CrateResult<DigitSequence> {
+        let hex = hex.as_bytes();
+
+        if !hex.len().is_multiple_of(2) {
+            return Err(CrateError::InvalidEncoding);
+        }
+
+        let bytes = hex
+            .chunks(2)
+            .map(|pair| match (hex_value(pair[0]), hex_value(pair[1])) {
+                (Some(high), Some(low)) => Ok((high << 4) | low),
+                _ => Err(CrateError::InvalidEncoding),
+            })
+            .collect::<CrateResult<Vec<u8>>>()?;
+
+        Self::unpack(&bytes)
+    }
+
+    /// Unpadded, URL-safe base64 representation of the [packed](DigitSequence::pack) digits -
+    /// the most compact text form.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence: DigitSequence = 12345u16.into();
+    /// assert_eq!(sequence.to_packed_base64(), "EjRf");
+    ///
+    /// let sequence: DigitSequence = 9u8.into();
+    /// assert_eq!(sequence.to_packed_base64(), "nw");
+    /// ```
+    pub fn to_packed_base64(&self) -> String {
+        let mut result = String::new();
+
+        for chunk in self.pack().chunks(3) {
+            let buffer = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |buffer, (index, &byte)| {
+                    buffer | ((byte as u32) << (16 - 8 * index))
+                });
+
+            for symbol_index in 0..=chunk.len() {
+                let value = (buffer >> (18 - 6 * symbol_index)) & 0x3F;
+                result.push(BASE64_ALPHABET[value as usize] as char);
+            }
+        }
+
+        result
+    }
+
+    /// Creates a sequence from the string returned by
+    /// [to_packed_base64](DigitSequence::to_packed_base64).
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(DigitSequence::from_packed_base64("EjRf")?, [1, 2, 3, 4, 5]);
+    ///
+    /// let sequence: DigitSequence = "000123456789".parse()?;
+    /// assert_eq!(DigitSequence::from_packed_base64(&sequence.to_packed_base64())?, sequence);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Malformed input - including padded base64 - results in a [CrateError::InvalidEncoding]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// assert_eq!(DigitSequence::from_packed_base64("nw=="), Err(CrateError::InvalidEncoding));
+    /// assert_eq!(DigitSequence::from_packed_base64("E"), Err(CrateError::InvalidEncoding));
+    /// ```
+    pub fn from_packed_base64(base64: &str) -> CrateResult<DigitSequence> {
+        let mut bytes = Vec::with_capacity(base64.len() * 3 / 4);
+
+        for chunk in base64.as_bytes().chunks(4) {
+            if chunk.len() == 1 {
+                return Err(CrateError::InvalidEncoding);
+            }
+
+            let mut buffer = 0u32;
+
+            for (index, &symbol) in chunk.iter().enumerate() {
+                let value = base64_value(symbol).ok_or(CrateError::InvalidEncoding)?;
+                buffer |= (value as u32) << (18 - 6 * index);
+            }
+
+            let byte_count = chunk.len() - 1;
+
+            if buffer & (0xFF_FF_FF >> (8 * byte_count)) != 0 {
+                return Err(CrateError::InvalidEncoding);
+            }
+
+            for index in 0..byte_count {
+                bytes.push((buffer >> (16 - 8 * index)) as u8);
+            }
+        }
+
+        Self::unpack(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Packing" {
+            it "should round-trip through every text form, for every length" {
+                for sequence in short_sequences(12) {
+                    eq!(DigitSequence::unpack(&sequence.pack()).unwrap(), sequence);
+                    eq!(DigitSequence::from_packed_hex(&sequence.to_packed_hex()).unwrap(), sequence);
+                    eq!(DigitSequence::from_packed_base64(&sequence.to_packed_base64()).unwrap(), sequence);
+                }
+            }
+
+            it "should reject non-canonical trailing base64 bits" {
+                eq!(DigitSequence::from_packed_base64("nx"), Err(CrateError::InvalidEncoding));
+            }
+        }
+    }
+}
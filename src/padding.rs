@@ -0,0 +1,118 @@
+use crate::result::require_len;
+use crate::{CrateResult, DigitSequence, Digits};
+
+impl DigitSequence {
+    /// New sequence having exactly `width` digits, obtained by prepending zeros -
+    /// which preserves the numeric value, as required by fixed-width fields
+    /// such as account numbers; a longer sequence results in a
+    /// [CrateError::LengthMismatch](crate::CrateError::LengthMismatch).
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 4072u16.into();
+    ///
+    /// assert_eq!(sequence.pad_left(6)?, [0, 0, 4, 0, 7, 2]);
+    /// assert_eq!(sequence.pad_left(4)?, [4, 0, 7, 2]);
+    /// assert_eq!(sequence.pad_left(3), Err(CrateError::LengthMismatch { expected: 3, actual: 4 }));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pad_left(&self, width: usize) -> CrateResult<DigitSequence> {
+        require_len(self.0.len(), 0..=width)?;
+
+        Ok(self.pad_left_truncating(width))
+    }
+
+    /// Just like [pad_left](DigitSequence::pad_left) - but a longer
+    /// sequence is truncated to its last `width` digits.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence: DigitSequence = 4072u16.into();
+    ///
+    /// assert_eq!(sequence.pad_left_truncating(6), [0, 0, 4, 0, 7, 2]);
+    /// assert_eq!(sequence.pad_left_truncating(3), [0, 7, 2]);
+    /// ```
+    pub fn pad_left_truncating(&self, width: usize) -> DigitSequence {
+        let kept = &self.0[self.0.len().saturating_sub(width)..];
+
+        let mut digits = Digits::with_capacity(width);
+        digits.extend(core::iter::repeat_n(0, width - kept.len()));
+        digits.extend_from_slice(kept);
+
+        DigitSequence(digits)
+    }
+
+    /// New sequence having exactly `width` digits, obtained by appending zeros;
+    /// a longer sequence results in a [CrateError::LengthMismatch](crate::CrateError::LengthMismatch).
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 4072u16.into();
+    ///
+    /// assert_eq!(sequence.pad_right(6)?, [4, 0, 7, 2, 0, 0]);
+    /// assert_eq!(sequence.pad_right(3), Err(CrateError::LengthMismatch { expected: 3, actual: 4 }));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pad_right(&self, width: usize) -> CrateResult<DigitSequence> {
+        require_len(self.0.len(), 0..=width)?;
+
+        Ok(self.pad_right_truncating(width))
+    }
+
+    /// Just like [pad_right](DigitSequence::pad_right) - but a longer
+    /// sequence is truncated to its first `width` digits.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence: DigitSequence = 4072u16.into();
+    ///
+    /// assert_eq!(sequence.pad_right_truncating(6), [4, 0, 7, 2, 0, 0]);
+    /// assert_eq!(sequence.pad_right_truncating(3), [4, 0, 7]);
+    /// ```
+    pub fn pad_right_truncating(&self, width: usize) -> DigitSequence {
+        let kept = &self.0[..self.0.len().min(width)];
+
+        let mut digits = Digits::with_capacity(width);
+        digits.extend_from_slice(kept);
+        digits.resize(width, 0);
+
+        DigitSequence(digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Padding to a fixed width" {
+            it "should agree with the zero-padded formatting" {
+                for source in ["", "0", "7", "123456"] {
+                    let sequence: DigitSequence = source.parse().unwrap();
+
+                    for width in 0..8 {
+                        if source.len() <= width {
+                            eq!(sequence.pad_left(width).unwrap().to_string(), format!("{:0>width$}", source));
+                            eq!(sequence.pad_right(width).unwrap().to_string(), format!("{:0<width$}", source));
+                        }
+
+                        eq!(sequence.pad_left_truncating(width).iter().len(), width);
+                        eq!(sequence.pad_right_truncating(width).iter().len(), width);
+                    }
+                }
+            }
+        }
+    }
+}
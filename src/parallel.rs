@@ -0,0 +1,203 @@
+use crate::{CrateError, CrateResult, DigitSequence, Digits};
+use rayon::prelude::*;
+
+impl DigitSequence {
+    /// Sum of the digits - just like [digit_sum](DigitSequence::digit_sum),
+    /// but computed on all the cores.
+    ///
+    /// **REQUIRES FEATURE**: `rayon`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = "123456789".repeat(100_000).parse()?;
+    ///
+    /// assert_eq!(sequence.par_digit_sum(), 4_500_000);
+    /// assert_eq!(sequence.par_digit_sum(), sequence.digit_sum());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn par_digit_sum(&self) -> u64 {
+        self.0.par_iter().map(|&digit| digit as u64).sum()
+    }
+
+    /// How many times each 0-9 digit appears - the item at index `i`
+    /// referring to digit `i` - computed on all the cores.
+    ///
+    /// **REQUIRES FEATURE**: `rayon`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = "1220999".repeat(1000).parse()?;
+    ///
+    /// assert_eq!(
+    ///     sequence.par_digit_counts(),
+    ///     [1000, 1000, 2000, 0, 0, 0, 0, 0, 0, 3000]
+    /// );
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn par_digit_counts(&self) -> [usize; 10] {
+        self.0
+            .par_iter()
+            .fold(
+                || [0usize; 10],
+                |mut counts, &digit| {
+                    counts[digit as usize] += 1;
+                    counts
+                },
+            )
+            .reduce(
+                || [0usize; 10],
+                |mut left, right| {
+                    for (left_count, right_count) in left.iter_mut().zip(right) {
+                        *left_count += right_count;
+                    }
+
+                    left
+                },
+            )
+    }
+
+    /// Parses a byte string made of ASCII digits - just like
+    /// [from_ascii](DigitSequence::from_ascii), but validating and
+    /// converting the bytes on all the cores.
+    ///
+    /// Even in case of multiple non-digit bytes, the error always refers
+    /// to the first one.
+    ///
+    /// **REQUIRES FEATURE**: `rayon`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let digits = "0392".repeat(250_000);
+    ///
+    /// let sequence = DigitSequence::par_from_ascii(digits.as_bytes())?;
+    /// assert_eq!(sequence.iter().len(), 1_000_000);
+    ///
+    /// let invalid = format!("{}x{}y", digits, digits);
+    /// assert_eq!(
+    ///     DigitSequence::par_from_ascii(invalid.as_bytes()),
+    ///     Err(CrateError::NonDigitChar('x'))
+    /// );
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn par_from_ascii(bytes: &[u8]) -> CrateResult<DigitSequence> {
+        if let Some(&byte) = bytes.par_iter().find_first(|byte| !byte.is_ascii_digit()) {
+            return Err(CrateError::NonDigitChar(char::from(byte)));
+        }
+
+        let mut digits = Digits::from(bytes);
+        digits.par_iter_mut().for_each(|byte| *byte -= b'0');
+
+        Ok(DigitSequence(digits))
+    }
+}
+
+/// Parallel, consuming iteration on [DigitSequence] is supported.
+///
+/// **REQUIRES FEATURE**: `rayon`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use rayon::prelude::*;
+///
+/// let sequence: DigitSequence = 9072u16.into();
+/// let doubled: Vec<u8> = sequence.into_par_iter().map(|digit| digit * 2).collect();
+///
+/// assert_eq!(doubled, [18, 0, 14, 4]);
+/// ```
+impl IntoParallelIterator for DigitSequence {
+    type Item = u8;
+
+    type Iter = rayon::vec::IntoIter<u8>;
+
+    #[cfg(not(feature = "smallvec"))]
+    fn into_par_iter(self) -> Self::Iter {
+        self.0.into_par_iter()
+    }
+
+    #[cfg(feature = "smallvec")]
+    fn into_par_iter(self) -> Self::Iter {
+        self.0.into_vec().into_par_iter()
+    }
+}
+
+/// Parallel, reference-based iteration on &[DigitSequence] is supported -
+/// which also provides `par_iter()`.
+///
+/// **REQUIRES FEATURE**: `rayon`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use rayon::prelude::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: DigitSequence = "31415926535".repeat(10_000).parse()?;
+///
+/// let fives = sequence.par_iter().filter(|&&digit| digit == 5).count();
+/// assert_eq!(fives, 30_000);
+///
+/// # Ok(())
+/// # }
+/// ```
+impl<'a> IntoParallelIterator for &'a DigitSequence {
+    type Item = &'a u8;
+
+    type Iter = rayon::slice::Iter<'a, u8>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.0.par_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Parallel statistics" {
+            it "should agree with the sequential computation" {
+                let sequence: DigitSequence = "8675309".repeat(50_000).parse().unwrap();
+
+                let mut counts = [0usize; 10];
+                for &digit in sequence.iter() {
+                    counts[digit as usize] += 1;
+                }
+
+                eq!(sequence.par_digit_sum(), sequence.digit_sum());
+                eq!(sequence.par_digit_counts(), counts);
+            }
+
+            it "should support the empty sequence" {
+                let sequence = DigitSequence::new();
+
+                eq!(sequence.par_digit_sum(), 0);
+                eq!(sequence.par_digit_counts(), [0; 10]);
+                eq!(DigitSequence::par_from_ascii(b""), Ok(sequence));
+            }
+        }
+
+        describe "Parallel parsing" {
+            it "should agree with the sequential parsing" {
+                let source = "0123456789".repeat(20_000);
+
+                eq!(
+                    DigitSequence::par_from_ascii(source.as_bytes()),
+                    DigitSequence::from_ascii(source.as_bytes())
+                );
+            }
+        }
+    }
+}
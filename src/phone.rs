@@ -0,0 +1,103 @@
+use crate::{CrateError, CrateResult, DigitSequence, Digits};
+
+/// Characters commonly used to format phone numbers.
+const PHONE_SEPARATORS: [char; 6] = [' ', '.', '(', ')', '-', '/'];
+
+impl DigitSequence {
+    /// Parses a phone number, ignoring the characters commonly used to
+    /// format it - spaces, dots, slashes, parentheses and dashes - as well as
+    /// a leading `+`; any other character results in a [CrateError::NonDigitChar],
+    /// while a string without digits results in a [CrateError::EmptySequence].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(DigitSequence::from_phone_str("(555) 123-4567")?.to_string(), "5551234567");
+    /// assert_eq!(DigitSequence::from_phone_str("+39 02.1234.5678")?.to_string(), "390212345678");
+    ///
+    /// assert_eq!(DigitSequence::from_phone_str("555 12+34"), Err(CrateError::NonDigitChar('+')));
+    /// assert_eq!(DigitSequence::from_phone_str("555-CALL"), Err(CrateError::NonDigitChar('C')));
+    /// assert_eq!(DigitSequence::from_phone_str("( )"), Err(CrateError::EmptySequence));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_phone_str(s: &str) -> CrateResult<DigitSequence> {
+        DigitSequence::from_phone_str_with_plus(s).map(|(sequence, _)| sequence)
+    }
+
+    /// Parses a phone number just like [from_phone_str](DigitSequence::from_phone_str),
+    /// also telling whether it starts with `+` - usually introducing
+    /// an international prefix.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let (sequence, has_plus) = DigitSequence::from_phone_str_with_plus(" +1 (555) 123-4567")?;
+    /// assert_eq!(sequence.to_string(), "15551234567");
+    /// assert!(has_plus);
+    ///
+    /// let (sequence, has_plus) = DigitSequence::from_phone_str_with_plus("0039 02 1234")?;
+    /// assert_eq!(sequence.to_string(), "0039021234");
+    /// assert!(!has_plus);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_phone_str_with_plus(s: &str) -> CrateResult<(DigitSequence, bool)> {
+        let trimmed = s.trim_start_matches(PHONE_SEPARATORS);
+
+        let (number, has_plus) = match trimmed.strip_prefix('+') {
+            Some(number) => (number, true),
+            None => (trimmed, false),
+        };
+
+        let mut digits = Digits::with_capacity(number.len());
+
+        for current_char in number.chars() {
+            match current_char {
+                '0'..='9' => digits.push(current_char as u8 - b'0'),
+                _ if PHONE_SEPARATORS.contains(&current_char) => (),
+                _ => return Err(CrateError::NonDigitChar(current_char)),
+            }
+        }
+
+        if digits.is_empty() {
+            return Err(CrateError::EmptySequence);
+        }
+
+        Ok((DigitSequence(digits), has_plus))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Parsing a phone number" {
+            it "should ignore separators around the leading plus" {
+                let (sequence, has_plus) = DigitSequence::from_phone_str_with_plus("( +44) 20-7946.0018").unwrap();
+
+                eq!(sequence.to_string(), "442079460018");
+                eq!(has_plus, true);
+            }
+
+            it "should reject a repeated plus" {
+                eq!(DigitSequence::from_phone_str("++39 02"), Err(CrateError::NonDigitChar('+')));
+            }
+
+            it "should reject a lone plus" {
+                eq!(DigitSequence::from_phone_str_with_plus(" + "), Err(CrateError::EmptySequence));
+            }
+
+            it "should reject other whitespace" {
+                eq!(DigitSequence::from_phone_str("555\t1234"), Err(CrateError::NonDigitChar('\t')));
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{DigitSequence, DigitSlice};
+
+impl<'a> DigitSlice<'a> {
+    /// Just like [DigitSequence::place_values].
+    pub fn place_values(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (u8, usize)> + ExactSizeIterator + 'a {
+        let places = (0..self.0.len()).rev();
+
+        self.0.iter().copied().zip(places)
+    }
+
+    /// Just like [DigitSequence::weighted_values].
+    pub fn weighted_values(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = Option<u128>> + ExactSizeIterator + 'a {
+        self.place_values().map(|(digit, place)| match digit {
+            0 => Some(0),
+            _ => u32::try_from(place)
+                .ok()
+                .and_then(|place| 10u128.checked_pow(place))
+                .and_then(|weight| weight.checked_mul(digit as u128)),
+        })
+    }
+}
+
+impl DigitSequence {
+    /// Iterates over `(digit, place)` pairs, where `place` is the exponent of
+    /// the power of ten weighting the digit - from the most significant digit,
+    /// whose place is `len - 1`, to the least significant one, whose place is 0;
+    /// the opposite order is available via [Iterator::rev].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence: DigitSequence = 907u16.into();
+    ///
+    /// let pairs: Vec<(u8, usize)> = sequence.place_values().collect();
+    /// assert_eq!(pairs, [(9, 2), (0, 1), (7, 0)]);
+    ///
+    /// let pairs: Vec<(u8, usize)> = sequence.place_values().rev().collect();
+    /// assert_eq!(pairs, [(7, 0), (0, 1), (9, 2)]);
+    /// ```
+    pub fn place_values(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (u8, usize)> + ExactSizeIterator + '_ {
+        self.as_digit_slice().place_values()
+    }
+
+    /// Iterates over the weighted value of each digit - that is, the digit
+    /// multiplied by the power of ten of its place - in the same order as
+    /// [place_values](DigitSequence::place_values); each value is [None] if
+    /// it overflows [u128], while zeros are always [Some].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence: DigitSequence = 907u16.into();
+    ///
+    /// let values: Vec<Option<u128>> = sequence.weighted_values().collect();
+    /// assert_eq!(values, [Some(900), Some(0), Some(7)]);
+    ///
+    /// let huge: DigitSequence = "5".repeat(40).parse().unwrap();
+    /// assert_eq!(huge.weighted_values().next(), Some(None));
+    /// ```
+    pub fn weighted_values(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = Option<u128>> + ExactSizeIterator + '_ {
+        self.as_digit_slice().weighted_values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Weighted values" {
+            it "should sum up to the numeric value" {
+                let sequence: DigitSequence = u128::MAX.into();
+
+                let sum: u128 = sequence.weighted_values().map(Option::unwrap).sum();
+
+                eq!(sum, u128::MAX);
+            }
+
+            it "should keep leading zeros beyond the u128 range" {
+                let sequence: DigitSequence = format!("{}1", "0".repeat(50)).parse().unwrap();
+
+                eq!(sequence.weighted_values().flatten().sum::<u128>(), 1);
+                eq!(sequence.weighted_values().len(), 51);
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::DigitSequence;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use sqlx::{Decode, Encode, Type, TypeInfo, ValueRef};
+
+/// Sign of a non-negative NUMERIC, in the Postgres binary format.
+const NUMERIC_POSITIVE: u16 = 0x0000;
+
+/// Decodes a NUMERIC in the Postgres binary format - a header followed by
+/// base-10000 digit groups - as long as it is a non-negative integer.
+fn decode_binary_numeric(bytes: &[u8]) -> Result<DigitSequence, BoxDynError> {
+    let header = |index: usize| -> Result<u16, BoxDynError> {
+        bytes
+            .get(index * 2..index * 2 + 2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .ok_or_else(|| "truncated NUMERIC value".into())
+    };
+
+    let group_count = header(0)? as usize;
+    let weight = header(1)? as i16;
+    let sign = header(2)?;
+
+    if sign != NUMERIC_POSITIVE {
+        return Err("only non-negative NUMERIC values can be decoded".into());
+    }
+
+    if group_count == 0 {
+        return Ok(DigitSequence::from(0u8));
+    }
+
+    if weight < 0 || (weight as usize) < group_count - 1 {
+        return Err("only integer NUMERIC values can be decoded".into());
+    }
+
+    let mut text = String::with_capacity((weight as usize + 1) * 4);
+
+    for group_index in 0..=weight as usize {
+        let group = if group_index < group_count {
+            header(4 + group_index)?
+        } else {
+            0
+        };
+
+        if group_index == 0 {
+            text.push_str(&group.to_string());
+        } else {
+            text.push_str(&format!("{:04}", group));
+        }
+    }
+
+    Ok(DigitSequence::from_ascii(text.as_bytes())?)
+}
+
+/// With the `sqlx-postgres` feature, [DigitSequence] maps to `TEXT` columns -
+/// and can also be read from integer `NUMERIC` columns; to write a `NUMERIC`
+/// column, just cast the parameter in SQL - e.g. `$1::numeric`.
+///
+/// **REQUIRES FEATURE**: `sqlx-postgres`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use sqlx::postgres::Postgres;
+/// use sqlx::{Type, TypeInfo};
+///
+/// assert_eq!(<DigitSequence as Type<Postgres>>::type_info().name(), "TEXT");
+/// ```
+impl Type<Postgres> for DigitSequence {
+    fn type_info() -> PgTypeInfo {
+        <&str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <&str as Type<Postgres>>::compatible(ty) || ty.name() == "NUMERIC"
+    }
+}
+
+impl Encode<'_, Postgres> for DigitSequence {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend(self.to_ascii_vec());
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Decode<'_, Postgres> for DigitSequence {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let is_binary_numeric =
+            value.format() == PgValueFormat::Binary && value.type_info().name() == "NUMERIC";
+
+        if is_binary_numeric {
+            decode_binary_numeric(value.as_bytes()?)
+        } else {
+            Ok(DigitSequence::from_ascii(value.as_bytes()?)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    fn binary_numeric(weight: i16, sign: u16, groups: &[u16]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        for header in [groups.len() as u16, weight as u16, sign, 0] {
+            bytes.extend(header.to_be_bytes());
+        }
+
+        for group in groups {
+            bytes.extend(group.to_be_bytes());
+        }
+
+        bytes
+    }
+
+    speculate! {
+        describe "Decoding binary NUMERIC" {
+            it "should decode zero" {
+                eq!(decode_binary_numeric(&binary_numeric(0, 0, &[])).unwrap(), [0]);
+            }
+
+            it "should pad inner groups and restore trailing zero groups" {
+                let bytes = binary_numeric(3, 0, &[12, 7]);
+
+                eq!(decode_binary_numeric(&bytes).unwrap().to_string(), "12000700000000");
+            }
+
+            it "should reject negative values" {
+                assert!(decode_binary_numeric(&binary_numeric(0, 0x4000, &[5])).is_err());
+            }
+
+            it "should reject fractional values" {
+                assert!(decode_binary_numeric(&binary_numeric(0, 0, &[5, 2500])).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::arithmetic::{compare, div_rem, mul, normalize, significant_digits, sub_in_place};
+use crate::{DigitSequence, Digits};
+use alloc::vec;
+use core::cmp::Ordering;
+
+fn mul_mod(left: &[u8], right: &[u8], modulus: &[u8]) -> Digits {
+    div_rem(&mul(left, right), modulus).1
+}
+
+/// Computes `base ^ exponent mod modulus`, consuming the decimal
+/// digits of the exponent from the most significant one.
+fn pow_mod(base: &[u8], exponent: &[u8], modulus: &[u8]) -> Digits {
+    let mut base_powers = vec![Digits::from(&[1][..])];
+
+    for _ in 1..10 {
+        let next_power = mul_mod(base_powers.last().unwrap(), base, modulus);
+        base_powers.push(next_power);
+    }
+
+    let one = base_powers[0].clone();
+
+    exponent.iter().fold(one, |result, &digit| {
+        let squared = mul_mod(&result, &result, modulus);
+        let fourth = mul_mod(&squared, &squared, modulus);
+        let fifth = mul_mod(&fourth, &result, modulus);
+        let tenth = mul_mod(&fifth, &fifth, modulus);
+
+        mul_mod(&tenth, &base_powers[digit as usize], modulus)
+    })
+}
+
+fn is_small_prime(value: u64) -> bool {
+    value >= 2
+        && (2..)
+            .take_while(|factor| factor * factor <= value)
+            .all(|factor| !value.is_multiple_of(factor))
+}
+
+impl DigitSequence {
+    /// Runs the Miller-Rabin primality test on the represented value,
+    /// with arbitrary precision - using the first `rounds` primes as witnesses.
+    ///
+    /// A `false` result is always correct, whereas `true` means *probably prime*;
+    /// however, with at least 13 rounds the result is exact for any value
+    /// below 3.3 × 10<sup>24</sup>.
+    ///
+    /// At least one round is always performed - so `0` rounds behave just like `1`.
+    ///
+    /// **REQUIRES FEATURE**: `primality`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let prime: DigitSequence = 1_000_000_007u32.into();
+    /// assert!(prime.is_probably_prime(13));
+    ///
+    /// let carmichael: DigitSequence = 561u16.into();
+    /// assert!(!carmichael.is_probably_prime(13));
+    ///
+    /// let mersenne_prime: DigitSequence = "170141183460469231731687303715884105727".parse()?;
+    /// assert!(mersenne_prime.is_probably_prime(5));
+    ///
+    /// let mersenne_composite: DigitSequence = (u128::MAX / 4 + 1).into();
+    /// assert!(!mersenne_composite.is_probably_prime(5));
+    ///
+    /// assert!(!DigitSequence::new().is_probably_prime(5));
+    /// assert!(!DigitSequence::from(1u8).is_probably_prime(5));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_probably_prime(&self, rounds: usize) -> bool {
+        let candidate = significant_digits(&self.0);
+
+        if candidate.len() <= 18 {
+            let value: u64 = self.try_into().unwrap();
+
+            if value < 100 {
+                return is_small_prime(value);
+            }
+        }
+
+        if candidate.last().unwrap().is_multiple_of(2) {
+            return false;
+        }
+
+        let mut predecessor = Digits::from(candidate);
+        sub_in_place(&mut predecessor, &[1]);
+
+        let mut odd_factor = predecessor.clone();
+        let mut two_exponent = 0;
+
+        while odd_factor.last().unwrap().is_multiple_of(2) {
+            odd_factor = div_rem(&odd_factor, &[2]).0;
+            two_exponent += 1;
+        }
+
+        let witnesses = (2u64..)
+            .filter(|&value| is_small_prime(value))
+            .take(rounds.max(1));
+
+        'witnesses: for witness in witnesses {
+            let witness = DigitSequence::from(witness).0;
+
+            if compare(&witness, &predecessor) != Ordering::Less {
+                break;
+            }
+
+            let mut power = normalize(pow_mod(&witness, &odd_factor, candidate));
+
+            if power[..] == [1] || power == predecessor {
+                continue;
+            }
+
+            for _ in 1..two_exponent {
+                power = mul_mod(&power, &power, candidate);
+
+                if power == predecessor {
+                    continue 'witnesses;
+                }
+            }
+
+            return false;
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Testing primality" {
+            it "should perform at least one round" {
+                assert!(!DigitSequence::from(561u16).is_probably_prime(0));
+                assert!(DigitSequence::from(101u8).is_probably_prime(0));
+            }
+
+            it "should match trial division below 3000" {
+                for value in 0u16..3000 {
+                    let sequence = DigitSequence::from(value);
+
+                    eq!(sequence.is_probably_prime(13), is_small_prime(value as u64), "{}", value);
+                }
+            }
+
+            it "should detect strong pseudoprimes to the first bases" {
+                let strong_pseudoprime: DigitSequence = 3215031751u64.into();
+
+                assert!(strong_pseudoprime.is_probably_prime(4));
+                assert!(!strong_pseudoprime.is_probably_prime(5));
+            }
+
+            it "should ignore leading zeros" {
+                let sequence: DigitSequence = "000101".parse().unwrap();
+
+                assert!(sequence.is_probably_prime(13));
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::DigitSequence;
+use quickcheck::{Arbitrary, Gen};
+
+/// Values to try, when shrinking a digit: zero, its half and its predecessor.
+fn lower_digits(digit: u8) -> impl Iterator<Item = u8> {
+    let mut candidates = vec![0, digit / 2, digit.saturating_sub(1)];
+    candidates.dedup();
+
+    candidates
+        .into_iter()
+        .filter(move |&candidate| candidate < digit)
+}
+
+/// With the `quickcheck` feature, [DigitSequence] can be generated
+/// by [quickcheck](https://crates.io/crates/quickcheck) - with a length
+/// up to the size of the generator.
+///
+/// Shrinking first removes digits, then lowers their values - so that
+/// counterexamples are minimized to short sequences of small digits.
+///
+/// **REQUIRES FEATURE**: `quickcheck`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use quickcheck::{Arbitrary, QuickCheck};
+///
+/// fn roundtrip_via_string(sequence: DigitSequence) -> bool {
+///     sequence.to_string().parse::<DigitSequence>() == Ok(sequence)
+/// }
+///
+/// QuickCheck::new().quickcheck(roundtrip_via_string as fn(DigitSequence) -> bool);
+///
+/// let sequence: DigitSequence = [3, 0].try_into().unwrap();
+/// let shrunk: Vec<String> = sequence.shrink().map(|shrunk| shrunk.to_string()).collect();
+///
+/// assert_eq!(shrunk, vec!["0", "3", "00", "10", "20"]);
+/// ```
+impl Arbitrary for DigitSequence {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = usize::arbitrary(g) % (g.size() + 1);
+
+        let digits = (0..len)
+            .map(|_| *g.choose(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap())
+            .collect();
+
+        DigitSequence(digits)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let removal_source = self.0.clone();
+        let lowering_source = self.0.clone();
+
+        let removals = (0..self.0.len()).map(move |index| {
+            let mut digits = removal_source.clone();
+            digits.remove(index);
+
+            DigitSequence(digits)
+        });
+
+        let lowerings = (0..self.0.len()).flat_map(move |index| {
+            let source = lowering_source.clone();
+
+            lower_digits(source[index]).map(move |lowered| {
+                let mut digits = source.clone();
+                digits[index] = lowered;
+
+                DigitSequence(digits)
+            })
+        });
+
+        Box::new(removals.chain(lowerings))
+    }
+}
@@ -0,0 +1,408 @@
+use crate::arithmetic::{add, compare};
+use crate::{DigitSequence, Digits, BENFORD_EXPECTED_DISTRIBUTION};
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use rand::distributions::{Distribution, WeightedError, WeightedIndex};
+use rand::rngs::OsRng;
+use rand::Rng;
+
+/// Configurable [Distribution] of [DigitSequence] values, so that sequences
+/// can be sampled via [Rng::sample] and plugged into rand-based pipelines.
+///
+/// **REQUIRES FEATURE**: `rand`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use rand::rngs::StdRng;
+/// use rand::{Rng, SeedableRng};
+///
+/// # fn main() -> GenericResult<()> {
+/// let mut rng = StdRng::seed_from_u64(90);
+///
+/// let distribution = DigitSequenceDistribution::new(8, true, [1.0; 10])?;
+///
+/// let sequence = rng.sample(&distribution);
+///
+/// assert_eq!(sequence.iter().len(), 8);
+/// assert_ne!(sequence.iter().next(), Some(&0));
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigitSequenceDistribution {
+    len: usize,
+    first_digit: WeightedIndex<f64>,
+}
+
+impl DigitSequenceDistribution {
+    /// Distribution of sequences having the given length, whose first digit
+    /// is drawn according to the relative `weights` of the 0-9 values - the other
+    /// digits being uniformly distributed; when `first_digit_nonzero` is set,
+    /// the weight of 0 is ignored, so that the first digit is never 0.
+    ///
+    /// The weights are validated - and prepared for sampling - just once:
+    /// just like [WeightedIndex::new], this constructor returns a [WeightedError]
+    /// if the weights actually in use are negative or have a zero total.
+    ///
+    /// **REQUIRES FEATURE**: `rand`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use rand::distributions::WeightedError;
+    ///
+    /// let mut weights = [0.0; 10];
+    /// weights[0] = 1.0;
+    ///
+    /// assert!(DigitSequenceDistribution::new(4, false, weights).is_ok());
+    ///
+    /// assert_eq!(
+    ///     DigitSequenceDistribution::new(4, true, weights),
+    ///     Err(WeightedError::AllWeightsZero)
+    /// );
+    /// ```
+    pub fn new(
+        len: usize,
+        first_digit_nonzero: bool,
+        weights: [f64; 10],
+    ) -> Result<DigitSequenceDistribution, WeightedError> {
+        let mut first_weights = weights;
+
+        if first_digit_nonzero {
+            first_weights[0] = 0.0;
+        }
+
+        let first_digit = WeightedIndex::new(first_weights)?;
+
+        Ok(DigitSequenceDistribution { len, first_digit })
+    }
+
+    /// Distribution of sequences having the given length, whose digits - including
+    /// the first one - are uniformly distributed.
+    ///
+    /// **REQUIRES FEATURE**: `rand`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use rand::Rng;
+    ///
+    /// let sequence = rand::thread_rng().sample(DigitSequenceDistribution::uniform(5));
+    ///
+    /// assert_eq!(sequence.iter().len(), 5);
+    /// ```
+    pub fn uniform(len: usize) -> DigitSequenceDistribution {
+        DigitSequenceDistribution::new(len, false, [1.0; 10])
+            .expect("Uniform weights must always be valid")
+    }
+
+    /// Distribution of sequences having the given length, whose first digit
+    /// follows [Benford's law](BENFORD_EXPECTED_DISTRIBUTION) - which is
+    /// convenient for realistic synthetic data.
+    ///
+    /// **REQUIRES FEATURE**: `rand`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use rand::rngs::StdRng;
+    /// use rand::{Rng, SeedableRng};
+    ///
+    /// let sequences: Vec<DigitSequence> = StdRng::seed_from_u64(90)
+    ///     .sample_iter(DigitSequenceDistribution::benford(6))
+    ///     .take(10_000)
+    ///     .collect();
+    ///
+    /// assert!(benford_deviation(&benford_distribution(&sequences)) < 0.005);
+    /// ```
+    pub fn benford(len: usize) -> DigitSequenceDistribution {
+        let mut weights = [0.0; 10];
+        weights[1..].copy_from_slice(&BENFORD_EXPECTED_DISTRIBUTION);
+
+        DigitSequenceDistribution::new(len, true, weights)
+            .expect("Benford weights must always be valid")
+    }
+}
+
+impl Distribution<DigitSequence> for DigitSequenceDistribution {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DigitSequence {
+        if self.len == 0 {
+            return DigitSequence::new();
+        }
+
+        let first_digit = self.first_digit.sample(rng) as u8;
+
+        let mut digits = Digits::with_capacity(self.len);
+        digits.push(first_digit);
+        digits.extend((1..self.len).map(|_| rng.gen_range(0..10u8)));
+
+        DigitSequence(digits)
+    }
+}
+
+impl DigitSequence {
+    /// Creates a sequence of the given length, whose digits are uniformly
+    /// distributed and drawn from the operating system's cryptographically
+    /// secure random number generator - so it is suitable for PINs
+    /// and verification codes.
+    ///
+    /// **REQUIRES FEATURE**: `rand`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let pin = DigitSequence::random(6);
+    ///
+    /// assert_eq!(pin.iter().len(), 6);
+    /// assert!(pin.iter().all(|&digit| digit < 10));
+    /// ```
+    pub fn random(len: usize) -> DigitSequence {
+        DigitSequence::random_with(&mut OsRng, len)
+    }
+
+    /// Creates a sequence of the given length, whose digits are uniformly
+    /// distributed - with no modulo bias - and drawn from the given
+    /// random number generator.
+    ///
+    /// **REQUIRES FEATURE**: `rand`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let first = DigitSequence::random_with(&mut StdRng::seed_from_u64(90), 20);
+    /// let second = DigitSequence::random_with(&mut StdRng::seed_from_u64(90), 20);
+    ///
+    /// assert_eq!(first.iter().len(), 20);
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R, len: usize) -> DigitSequence {
+        DigitSequence((0..len).map(|_| rng.gen_range(0..10)).collect())
+    }
+
+    /// Creates a sequence whose numeric value is uniformly distributed between
+    /// `low` and `high` - both inclusive - drawing from the operating system's
+    /// cryptographically secure random number generator.
+    ///
+    /// Just like the result of arithmetic operations, the returned sequence
+    /// has no leading zeros.
+    ///
+    /// **REQUIRES FEATURE**: `rand`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let low: DigitSequence = "0100".parse()?;
+    /// let high: DigitSequence = 250u8.into();
+    ///
+    /// let value = DigitSequence::random_in_range(&low, &high);
+    /// let value: u8 = value.try_into()?;
+    ///
+    /// assert!((100..=250).contains(&value));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Just like [Rng::gen_range], this method panics if `low` is greater than `high`.
+    pub fn random_in_range(low: &DigitSequence, high: &DigitSequence) -> DigitSequence {
+        DigitSequence::random_in_range_with(&mut OsRng, low, high)
+    }
+
+    /// Creates a sequence whose numeric value is uniformly distributed between
+    /// `low` and `high` - both inclusive - drawing from the given random number generator.
+    ///
+    /// **REQUIRES FEATURE**: `rand`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let low: DigitSequence = "99999999999999999999999999999999999999999".parse()?;
+    /// let high: DigitSequence = "100000000000000000000000000000000000000009".parse()?;
+    ///
+    /// let value = DigitSequence::random_in_range_with(&mut StdRng::seed_from_u64(90), &low, &high);
+    ///
+    /// assert!(value.checked_sub(&low).is_ok());
+    /// assert!(high.checked_sub(&value).is_ok());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Just like [Rng::gen_range], this method panics if `low` is greater than `high`.
+    pub fn random_in_range_with<R: Rng + ?Sized>(
+        rng: &mut R,
+        low: &DigitSequence,
+        high: &DigitSequence,
+    ) -> DigitSequence {
+        let span = high
+            .checked_sub(low)
+            .expect("The low bound must not be greater than the high bound");
+
+        let offset = loop {
+            let candidate = DigitSequence::random_with(rng, span.0.len());
+
+            if compare(&candidate.0, &span.0) != Ordering::Greater {
+                break candidate;
+            }
+        };
+
+        DigitSequence(add(&low.0, &offset.0))
+    }
+}
+
+/// Infinite iterator over uniformly distributed digits - with no modulo bias -
+/// drawn from the given random number generator: handy for Monte Carlo
+/// simulations and for lazily generating arbitrarily long test sequences.
+///
+/// **REQUIRES FEATURE**: `rand`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// # fn main() -> GenericResult<()> {
+/// let mut digits = RandomDigits::new(StdRng::seed_from_u64(90));
+///
+/// let pin = digits.take_sequence(4)?;
+/// assert_eq!(pin.iter().len(), 4);
+///
+/// let sevens = digits.take(100_000).filter(|&digit| digit == 7).count();
+/// assert!((9_500..=10_500).contains(&sevens));
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The generator can also be borrowed, via `RandomDigits::new(&mut rng)`.
+#[derive(Debug, Clone)]
+pub struct RandomDigits<R> {
+    rng: R,
+}
+
+impl<R: Rng> RandomDigits<R> {
+    /// Creates an iterator drawing from the given random number generator.
+    pub fn new(rng: R) -> RandomDigits<R> {
+        RandomDigits { rng }
+    }
+}
+
+impl<R: Rng> Iterator for RandomDigits<R> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        Some(self.rng.gen_range(0..10))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<R: Rng> FusedIterator for RandomDigits<R> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitStream;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use speculate2::*;
+
+    speculate! {
+        describe "Generating random sequences" {
+            it "should distribute the digits uniformly" {
+                let sequence = DigitSequence::random_with(&mut StdRng::seed_from_u64(7), 100_000);
+
+                let mut counts = [0usize; 10];
+
+                for &digit in sequence.iter() {
+                    counts[digit as usize] += 1;
+                }
+
+                assert!(counts.iter().all(|&count| (9_500..=10_500).contains(&count)));
+            }
+
+            it "should never sample a leading zero when required" {
+                let distribution = DigitSequenceDistribution::new(
+                    1,
+                    true,
+                    [100.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+                ).unwrap();
+
+                let mut rng = StdRng::seed_from_u64(7);
+
+                assert!((0..1000).all(|_| rng.sample(&distribution) == [9]));
+            }
+
+            it "should reject invalid weights on creation" {
+                assert_eq!(
+                    DigitSequenceDistribution::new(3, false, [0.0; 10]),
+                    Err(WeightedError::AllWeightsZero)
+                );
+
+                let mut weights = [1.0; 10];
+                weights[4] = -1.0;
+
+                assert_eq!(
+                    DigitSequenceDistribution::new(3, false, weights),
+                    Err(WeightedError::InvalidWeight)
+                );
+            }
+
+            it "should sample the empty sequence for zero length" {
+                let mut rng = StdRng::seed_from_u64(7);
+
+                assert_eq!(rng.sample(DigitSequenceDistribution::benford(0)), []);
+            }
+
+            it "should distribute values uniformly within a range" {
+                let mut rng = StdRng::seed_from_u64(7);
+                let low: DigitSequence = 10u8.into();
+                let high: DigitSequence = 14u8.into();
+
+                let mut counts = [0usize; 5];
+
+                for _ in 0..50_000 {
+                    let value: u8 = DigitSequence::random_in_range_with(&mut rng, &low, &high)
+                        .try_into()
+                        .unwrap();
+
+                    counts[(value - 10) as usize] += 1;
+                }
+
+                assert!(counts.iter().all(|&count| (9_500..=10_500).contains(&count)));
+            }
+
+            it "should return the only value of a degenerate range" {
+                let bound: DigitSequence = [0, 7].try_into().unwrap();
+
+                let value = DigitSequence::random_in_range_with(&mut StdRng::seed_from_u64(7), &bound, &bound);
+
+                assert_eq!(value, [7]);
+            }
+
+            it "should stream the same digits as a random sequence" {
+                let streamed = RandomDigits::new(StdRng::seed_from_u64(7)).take_sequence(50).unwrap();
+                let sequence = DigitSequence::random_with(&mut StdRng::seed_from_u64(7), 50);
+
+                assert_eq!(streamed, sequence);
+            }
+
+            it "should panic on an empty range" {
+                let low: DigitSequence = 8u8.into();
+                let high: DigitSequence = 7u8.into();
+
+                let result = std::panic::catch_unwind(|| {
+                    DigitSequence::random_in_range_with(&mut StdRng::seed_from_u64(7), &low, &high)
+                });
+
+                assert!(result.is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{CrateError, CrateResult, DigitSequence};
+use redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, RedisWrite, ToRedisArgs, Value};
+
+fn to_redis_error(error: CrateError) -> RedisError {
+    RedisError::from((
+        ErrorKind::TypeError,
+        "Response was not a digit sequence",
+        error.to_string(),
+    ))
+}
+
+/// With the `redis` feature, [DigitSequence] is written to Redis
+/// as its ASCII digit bytes - so it can be used as a key or a value.
+///
+/// **REQUIRES FEATURE**: `redis`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use redis::ToRedisArgs;
+///
+/// let sequence: DigitSequence = 9786u16.into();
+///
+/// assert_eq!(sequence.to_redis_args(), vec![b"9786".to_vec()]);
+/// ```
+impl ToRedisArgs for DigitSequence {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(&self.to_ascii_vec())
+    }
+}
+
+/// With the `redis` feature, [DigitSequence] can be read from Redis strings
+/// made of ASCII digits, as well as from non-negative integers.
+///
+/// **REQUIRES FEATURE**: `redis`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use redis::{FromRedisValue, Value};
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence = DigitSequence::from_redis_value(&Value::BulkString(b"0786".to_vec()))?;
+/// assert_eq!(sequence, [0, 7, 8, 6]);
+///
+/// let sequence = DigitSequence::from_redis_value(&Value::Int(90))?;
+/// assert_eq!(sequence, [9, 0]);
+///
+/// assert!(DigitSequence::from_redis_value(&Value::BulkString(b"07x".to_vec())).is_err());
+/// assert!(DigitSequence::from_redis_value(&Value::Int(-90)).is_err());
+/// assert!(DigitSequence::from_redis_value(&Value::Nil).is_err());
+///
+/// # Ok(())
+/// # }
+/// ```
+impl FromRedisValue for DigitSequence {
+    fn from_redis_value(value: &Value) -> RedisResult<Self> {
+        match value {
+            Value::BulkString(bytes) => DigitSequence::from_ascii(bytes).map_err(to_redis_error),
+
+            Value::SimpleString(text) => text.parse().map_err(to_redis_error),
+
+            Value::Int(number) => {
+                let result: CrateResult<DigitSequence> = (*number).try_into();
+
+                result.map_err(to_redis_error)
+            }
+
+            _ => Err(RedisError::from((
+                ErrorKind::TypeError,
+                "Response was of incompatible type",
+                format!("{:?}", value),
+            ))),
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use std::error::Error;
-use std::fmt::Display;
+use alloc::boxed::Box;
+use core::error::Error;
+use core::fmt::Display;
+use core::ops::RangeInclusive;
 
 /// The most generic [Error]-based [Result].
 pub type GenericResult<T> = Result<T, Box<dyn Error>>;
@@ -8,7 +10,11 @@ pub type GenericResult<T> = Result<T, Box<dyn Error>>;
 pub type CrateResult<T> = Result<T, CrateError>;
 
 /// Custom error scenarios related to this [crate].
+///
+/// New variants might be added as the crate evolves, so matching
+/// on this enum requires a wildcard arm outside of this crate.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub enum CrateError {
     /// When a character does not represent a 0-9 digit.
     NonDigitChar(char),
@@ -16,11 +22,49 @@ pub enum CrateError {
     /// When a number does not represent a 0-9 digit.
     NonDigitNumber(u128),
 
+    /// When a byte is not an ASCII 0-9 digit - which might also be
+    /// part of a multi-byte UTF-8 character.
+    NonDigitByte(u8),
+
     /// When trying to convert a negative number.
     NegativeNumber(i128),
 
     /// When an operation causes a numeric overflow.
     Overflow,
+
+    /// When an operation causes a numeric underflow - such as a subtraction
+    /// whose result would be negative.
+    Underflow,
+
+    /// When dividing by zero.
+    DivisionByZero,
+
+    /// When an operation requires a non-empty sequence.
+    EmptySequence,
+
+    /// When a sequence does not have the length required by an operation.
+    LengthMismatch {
+        /// The required length.
+        expected: usize,
+
+        /// The length actually found.
+        actual: usize,
+    },
+
+    /// When a word cannot be parsed as part of a number.
+    UnexpectedWord {
+        /// The byte offset of the word within the parsed string.
+        offset: usize,
+    },
+
+    /// When a string is not a valid Roman numeral.
+    InvalidRomanNumeral,
+
+    /// When packed or text-encoded data cannot be decoded as digits.
+    InvalidEncoding,
+
+    /// When digits do not represent an existing date or time.
+    InvalidDateTime,
 }
 
 /// [CrateError] has a string representation.
@@ -30,11 +74,26 @@ pub enum CrateError {
 ///
 /// assert_eq!(CrateError::NonDigitNumber(90).to_string(), "Non-digit number: 90");
 /// assert_eq!(CrateError::NonDigitChar('X').to_string(), "Non-digit char: X");
+/// assert_eq!(CrateError::NonDigitByte(0xE2).to_string(), "Non-digit byte: 0xE2");
 /// assert_eq!(CrateError::NegativeNumber(-90).to_string(), "Cannot convert negative number: -90");
 /// assert_eq!(CrateError::Overflow.to_string(), "Overflow");
+/// assert_eq!(CrateError::Underflow.to_string(), "Underflow");
+/// assert_eq!(CrateError::DivisionByZero.to_string(), "Division by zero");
+/// assert_eq!(CrateError::EmptySequence.to_string(), "Empty sequence");
+/// assert_eq!(
+///     CrateError::LengthMismatch { expected: 9, actual: 4 }.to_string(),
+///     "Length mismatch: expected 9, found 4"
+/// );
+/// assert_eq!(
+///     CrateError::UnexpectedWord { offset: 7 }.to_string(),
+///     "Unexpected word at offset 7"
+/// );
+/// assert_eq!(CrateError::InvalidRomanNumeral.to_string(), "Invalid Roman numeral");
+/// assert_eq!(CrateError::InvalidEncoding.to_string(), "Invalid encoding");
+/// assert_eq!(CrateError::InvalidDateTime.to_string(), "Invalid date/time");
 /// ```
 impl Display for CrateError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::NonDigitChar(non_digit_char) => write!(f, "Non-digit char: {}", non_digit_char),
 
@@ -42,13 +101,150 @@ impl Display for CrateError {
                 write!(f, "Non-digit number: {}", non_digit_number)
             }
 
+            Self::NonDigitByte(non_digit_byte) => {
+                write!(f, "Non-digit byte: {:#04X}", non_digit_byte)
+            }
+
             Self::NegativeNumber(number) => {
                 write!(f, "Cannot convert negative number: {}", number)
             }
 
             Self::Overflow => write!(f, "Overflow"),
+
+            Self::Underflow => write!(f, "Underflow"),
+
+            Self::DivisionByZero => write!(f, "Division by zero"),
+
+            Self::EmptySequence => write!(f, "Empty sequence"),
+
+            Self::LengthMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Length mismatch: expected {}, found {}",
+                    expected, actual
+                )
+            }
+
+            Self::UnexpectedWord { offset } => write!(f, "Unexpected word at offset {}", offset),
+
+            Self::InvalidRomanNumeral => write!(f, "Invalid Roman numeral"),
+
+            Self::InvalidEncoding => write!(f, "Invalid encoding"),
+
+            Self::InvalidDateTime => write!(f, "Invalid date/time"),
         }
     }
 }
 
 impl Error for CrateError {}
+
+/// Ensures that a length lies within the allowed range - otherwise returning
+/// a [CrateError::LengthMismatch] whose expected length is the upper bound.
+pub(crate) fn require_len(actual: usize, allowed: RangeInclusive<usize>) -> CrateResult<()> {
+    if allowed.contains(&actual) {
+        Ok(())
+    } else {
+        Err(CrateError::LengthMismatch {
+            expected: *allowed.end(),
+            actual,
+        })
+    }
+}
+
+/// [CrateError] can be converted to [std::io::Error], of kind
+/// [InvalidData](std::io::ErrorKind::InvalidData) - which is
+/// handy when parsing digits from I/O sources.
+///
+/// ```
+/// use digit_sequence::*;
+/// use std::io::{Error, ErrorKind};
+///
+/// let io_error: Error = CrateError::NonDigitChar('x').into();
+///
+/// assert_eq!(io_error.kind(), ErrorKind::InvalidData);
+/// assert_eq!(io_error.to_string(), "Non-digit char: x");
+/// ```
+#[cfg(feature = "std")]
+impl From<CrateError> for std::io::Error {
+    fn from(error: CrateError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+    }
+}
+
+/// With the `miette` feature, [CrateError] is a [miette::Diagnostic],
+/// providing an error code and a help message - as well as a label
+/// pointing to the offending position, whenever it is known.
+///
+/// **REQUIRES FEATURE**: `miette`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use miette::Diagnostic;
+///
+/// let error = CrateError::NonDigitChar('x');
+///
+/// assert_eq!(error.code().unwrap().to_string(), "digit_sequence::non_digit_char");
+/// assert!(error.help().is_some());
+/// assert!(error.labels().is_none());
+///
+/// let error = CrateError::UnexpectedWord { offset: 4 };
+/// let label = error.labels().unwrap().next().unwrap();
+///
+/// assert_eq!(label.offset(), 4);
+/// assert_eq!(label.label(), Some("unexpected word"));
+/// ```
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for CrateError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let code = match self {
+            Self::NonDigitChar(_) => "non_digit_char",
+            Self::NonDigitNumber(_) => "non_digit_number",
+            Self::NonDigitByte(_) => "non_digit_byte",
+            Self::NegativeNumber(_) => "negative_number",
+            Self::Overflow => "overflow",
+            Self::Underflow => "underflow",
+            Self::DivisionByZero => "division_by_zero",
+            Self::EmptySequence => "empty_sequence",
+            Self::LengthMismatch { .. } => "length_mismatch",
+            Self::UnexpectedWord { .. } => "unexpected_word",
+            Self::InvalidRomanNumeral => "invalid_roman_numeral",
+            Self::InvalidEncoding => "invalid_encoding",
+            Self::InvalidDateTime => "invalid_date_time",
+        };
+
+        Some(Box::new(format!("digit_sequence::{}", code)))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let help = match self {
+            Self::NonDigitChar(_) => "only the 0-9 characters are allowed",
+            Self::NonDigitNumber(_) => "only the numbers from 0 to 9 are allowed",
+            Self::NonDigitByte(_) => "only the ASCII 0-9 characters are allowed",
+            Self::NegativeNumber(_) => "only non-negative numbers can be converted",
+            Self::Overflow => "the value is too large for the requested type or operation",
+            Self::Underflow => "the result of the operation would be negative",
+            Self::DivisionByZero => "the divisor must not be zero",
+            Self::EmptySequence => "the sequence must contain at least one digit",
+            Self::LengthMismatch { .. } => "the number of digits must match the expected length",
+            Self::UnexpectedWord { .. } => {
+                "use English number words in descending order of magnitude"
+            }
+            Self::InvalidRomanNumeral => {
+                "use canonical Roman numerals, representing a value from 1 to 3999"
+            }
+            Self::InvalidEncoding => "the data must be produced by the matching encoding method",
+            Self::InvalidDateTime => "the digits must represent an existing calendar date and time",
+        };
+
+        Some(Box::new(help))
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        match self {
+            Self::UnexpectedWord { offset } => Some(Box::new(core::iter::once(
+                miette::LabeledSpan::at_offset(*offset, "unexpected word"),
+            ))),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{CrateError, CrateResult, DigitSequence};
+use alloc::string::String;
+
+const ROMAN_SYMBOLS: [(u16, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// The largest value representable in classical Roman numerals.
+const MAX_ROMAN_VALUE: u16 = 3999;
+
+fn to_roman(mut value: u16) -> String {
+    let mut result = String::new();
+
+    for (symbol_value, symbol) in ROMAN_SYMBOLS {
+        while value >= symbol_value {
+            result.push_str(symbol);
+            value -= symbol_value;
+        }
+    }
+
+    result
+}
+
+impl DigitSequence {
+    /// Converts the represented value to classical Roman numerals -
+    /// returning [None] if the value is outside the 1-3999 range.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 1994u16.into();
+    /// assert_eq!(sequence.to_roman(), Some("MCMXCIV".to_string()));
+    ///
+    /// let sequence: DigitSequence = [0, 4].try_into()?;
+    /// assert_eq!(sequence.to_roman(), Some("IV".to_string()));
+    ///
+    /// let sequence: DigitSequence = 4000u16.into();
+    /// assert_eq!(sequence.to_roman(), None);
+    ///
+    /// let sequence: DigitSequence = 0u8.into();
+    /// assert_eq!(sequence.to_roman(), None);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_roman(&self) -> Option<String> {
+        let value: u16 = self.try_into().ok()?;
+
+        (1..=MAX_ROMAN_VALUE)
+            .contains(&value)
+            .then(|| to_roman(value))
+    }
+
+    /// Parses classical Roman numerals - in canonical, case-insensitive form -
+    /// into a sequence without leading zeros.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence = DigitSequence::from_roman("MCMXCIV")?;
+    /// assert_eq!(sequence, [1, 9, 9, 4]);
+    ///
+    /// let sequence = DigitSequence::from_roman("xlii")?;
+    /// assert_eq!(sequence, [4, 2]);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Non-canonical forms and unknown symbols result in a [CrateError::InvalidRomanNumeral]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// assert_eq!(DigitSequence::from_roman("IIII"), Err(CrateError::InvalidRomanNumeral));
+    /// assert_eq!(DigitSequence::from_roman("IM"), Err(CrateError::InvalidRomanNumeral));
+    /// assert_eq!(DigitSequence::from_roman("XB"), Err(CrateError::InvalidRomanNumeral));
+    /// assert_eq!(DigitSequence::from_roman(""), Err(CrateError::InvalidRomanNumeral));
+    /// ```
+    pub fn from_roman(numeral: &str) -> CrateResult<DigitSequence> {
+        let numeral = numeral.to_uppercase();
+        let mut remaining = numeral.as_str();
+        let mut value = 0u16;
+
+        for (symbol_value, symbol) in ROMAN_SYMBOLS {
+            while let Some(rest) = remaining.strip_prefix(symbol) {
+                value += symbol_value;
+
+                if value > MAX_ROMAN_VALUE {
+                    return Err(CrateError::InvalidRomanNumeral);
+                }
+
+                remaining = rest;
+            }
+        }
+
+        if !remaining.is_empty() || value == 0 || to_roman(value) != numeral {
+            return Err(CrateError::InvalidRomanNumeral);
+        }
+
+        Ok(value.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Roman numerals" {
+            it "should support roundtrip over the whole range" {
+                for value in 1..=MAX_ROMAN_VALUE {
+                    let sequence = DigitSequence::from(value);
+                    let numeral = sequence.to_roman().unwrap();
+
+                    eq!(DigitSequence::from_roman(&numeral).unwrap(), sequence);
+                }
+            }
+
+            it "should reject values beyond the classical range" {
+                eq!(DigitSequence::from_roman("MMMM"), Err(CrateError::InvalidRomanNumeral));
+            }
+
+            it "should reject long inputs without overflowing" {
+                eq!(DigitSequence::from_roman(&"M".repeat(70)), Err(CrateError::InvalidRomanNumeral));
+            }
+        }
+    }
+}
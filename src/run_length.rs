@@ -0,0 +1,74 @@
+use crate::{CrateError, CrateResult, DigitSequence, Digits};
+use alloc::vec::Vec;
+
+impl DigitSequence {
+    /// Run-length encoding of the sequence: each item is a digit
+    /// followed by the number of its consecutive repetitions.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = [0, 0, 0, 7, 3, 3].try_into()?;
+    /// assert_eq!(sequence.run_length_encode(), vec![(0, 3), (7, 1), (3, 2)]);
+    ///
+    /// assert_eq!(DigitSequence::new().run_length_encode(), vec![]);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn run_length_encode(&self) -> Vec<(u8, usize)> {
+        let mut runs: Vec<(u8, usize)> = Vec::new();
+
+        for &digit in &self.0 {
+            match runs.last_mut() {
+                Some((run_digit, run_length)) if *run_digit == digit => *run_length += 1,
+                _ => runs.push((digit, 1)),
+            }
+        }
+
+        runs
+    }
+
+    /// Creates a sequence from its run-length encoding - the inverse
+    /// of [run_length_encode](DigitSequence::run_length_encode).
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence = DigitSequence::from_runs([(0, 3), (7, 1), (3, 2)])?;
+    /// assert_eq!(sequence, [0, 0, 0, 7, 3, 3]);
+    ///
+    /// let roundtrip = DigitSequence::from_runs(sequence.run_length_encode())?;
+    /// assert_eq!(roundtrip, sequence);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Out-of-range digits result in a [CrateError::NonDigitNumber]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let result = DigitSequence::from_runs([(1, 2), (10, 1)]);
+    /// assert_eq!(result, Err(CrateError::NonDigitNumber(10)));
+    /// ```
+    pub fn from_runs<I>(runs: I) -> CrateResult<DigitSequence>
+    where
+        I: IntoIterator<Item = (u8, usize)>,
+    {
+        let mut digits = Digits::new();
+
+        for (digit, run_length) in runs {
+            if digit >= 10 {
+                return Err(CrateError::NonDigitNumber(digit as u128));
+            }
+
+            digits.extend(core::iter::repeat_n(digit, run_length));
+        }
+
+        Ok(DigitSequence(digits))
+    }
+}
@@ -0,0 +1,132 @@
+use crate::DigitSequence;
+
+impl DigitSequence {
+    /// Equality check whose duration only depends on the length of the sequences,
+    /// not on their content - so that comparing secrets such as PINs
+    /// or one-time passwords does not leak timing information.
+    ///
+    /// The derived [PartialEq] returns as soon as a difference is found,
+    /// which is why it should not be used for secrets.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let expected_pin: DigitSequence = "0392".parse()?;
+    ///
+    /// assert!(expected_pin.ct_eq(&"0392".parse()?));
+    /// assert!(!expected_pin.ct_eq(&"0393".parse()?));
+    /// assert!(!expected_pin.ct_eq(&"039".parse()?));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ct_eq(&self, other: &DigitSequence) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+
+        let difference = self
+            .0
+            .iter()
+            .zip(&other.0)
+            .fold(0u8, |difference, (left, right)| {
+                core::hint::black_box(difference | (left ^ right))
+            });
+
+        difference == 0
+    }
+}
+
+/// Wrapper around a [DigitSequence] holding a secret - such as a card number,
+/// a PIN or a one-time password.
+///
+/// Both [Debug](std::fmt::Debug) and [Display](std::fmt::Display) are redacted,
+/// so the digits can only be read via an explicit call to
+/// [expose](SecretDigitSequence::expose):
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let card_number: DigitSequence = "4111111111111111".parse()?;
+/// let secret = SecretDigitSequence::from(card_number.clone());
+///
+/// assert_eq!(format!("{:?}", secret), "SecretDigitSequence([REDACTED])");
+/// assert_eq!(secret.to_string(), "[REDACTED]");
+///
+/// assert_eq!(secret.expose(), &card_number);
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Equality relies on [DigitSequence::ct_eq], and the whole buffer - up to
+/// its capacity - is overwritten with zeros when the wrapper is dropped;
+/// copies made *before* wrapping the sequence, however, are not affected.
+///
+/// For the same reason, the wrapper is not [Clone]: copying a secret
+/// requires an explicit call to [expose](SecretDigitSequence::expose).
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let pin: DigitSequence = "0392".parse()?;
+///
+/// let secret = SecretDigitSequence::from(pin.clone());
+/// assert_eq!(secret, SecretDigitSequence::from(pin));
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct SecretDigitSequence(DigitSequence);
+
+impl SecretDigitSequence {
+    /// Explicitly grants access to the secret digits.
+    pub fn expose(&self) -> &DigitSequence {
+        &self.0
+    }
+}
+
+impl From<DigitSequence> for SecretDigitSequence {
+    fn from(sequence: DigitSequence) -> SecretDigitSequence {
+        SecretDigitSequence(sequence)
+    }
+}
+
+impl PartialEq for SecretDigitSequence {
+    fn eq(&self, other: &SecretDigitSequence) -> bool {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl Eq for SecretDigitSequence {}
+
+impl core::fmt::Debug for SecretDigitSequence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SecretDigitSequence([REDACTED])")
+    }
+}
+
+impl core::fmt::Display for SecretDigitSequence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl Drop for SecretDigitSequence {
+    fn drop(&mut self) {
+        let digits = &mut self.0 .0;
+        let buffer = digits.as_mut_ptr();
+
+        for index in 0..digits.capacity() {
+            // SAFETY: the pointer stays within the allocated capacity,
+            // and any byte is a valid u8.
+            unsafe { buffer.add(index).write_volatile(0) };
+        }
+
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
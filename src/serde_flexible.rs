@@ -0,0 +1,56 @@
+//! Deserializes a [DigitSequence] from any of its common representations:
+//! an array of digits (`[9,7,8,6]`), a string (`"9786"`) or a non-negative number (`9786`);
+//! serialization still produces an array.
+//!
+//! **REQUIRES FEATURE**: `serde`.
+//!
+//! The module is meant to be referenced via `#[serde(with = ...)]`, and requires
+//! a self-describing format - such as JSON:
+//!
+//! ```
+//! use digit_sequence::*;
+//! use serde::{Deserialize, Serialize};
+//! use serde_json::{from_str, to_string};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Account {
+//!     #[serde(with = "digit_sequence::serde_flexible")]
+//!     code: DigitSequence,
+//! }
+//!
+//! # fn main() -> GenericResult<()> {
+//! let expected = Account { code: 9786u16.into() };
+//!
+//! assert_eq!(from_str::<Account>(r#"{"code":[9,7,8,6]}"#)?, expected);
+//! assert_eq!(from_str::<Account>(r#"{"code":"9786"}"#)?, expected);
+//! assert_eq!(from_str::<Account>(r#"{"code":9786}"#)?, expected);
+//!
+//! assert_eq!(to_string(&expected)?, r#"{"code":[9,7,8,6]}"#);
+//!
+//! assert!(from_str::<Account>(r#"{"code":-9786}"#).is_err());
+//! assert!(from_str::<Account>(r#"{"code":"97x"}"#).is_err());
+//! assert!(from_str::<Account>(r#"{"code":[9,17]}"#).is_err());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::serialization::DigitSequenceVisitor;
+use crate::DigitSequence;
+use serde::{Deserializer, Serialize, Serializer};
+
+/// Serializes the sequence just like its [Serialize] implementation.
+pub fn serialize<S>(sequence: &DigitSequence, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    sequence.serialize(serializer)
+}
+
+/// Deserializes a sequence from an array, a string or a number.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<DigitSequence, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(DigitSequenceVisitor)
+}
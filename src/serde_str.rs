@@ -0,0 +1,119 @@
+//! Serializes a [DigitSequence] as the concatenation of its digits -
+//! a string like `"0786"`, preserving leading zeros - instead of an array.
+//!
+//! **REQUIRES FEATURE**: `serde`.
+//!
+//! The module is meant to be referenced via `#[serde(with = ...)]`:
+//!
+//! ```
+//! use digit_sequence::*;
+//! use serde::{Deserialize, Serialize};
+//! use serde_json::{from_str, to_string};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Account {
+//!     #[serde(with = "digit_sequence::serde_str")]
+//!     code: DigitSequence,
+//! }
+//!
+//! # fn main() -> GenericResult<()> {
+//! let account = Account { code: "0786".parse()? };
+//!
+//! let json = to_string(&account)?;
+//! assert_eq!(json, r#"{"code":"0786"}"#);
+//!
+//! let deserialized: Account = from_str(&json)?;
+//! assert_eq!(deserialized, account);
+//!
+//! assert!(from_str::<Account>(r#"{"code":"07x"}"#).is_err());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::DigitSequence;
+use alloc::string::String;
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::Serializer;
+
+/// Serializes the sequence as its string representation.
+pub fn serialize<S>(sequence: &DigitSequence, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(sequence)
+}
+
+/// Deserializes a sequence from a string of 0-9 digits.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<DigitSequence, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(D::Error::custom)
+}
+
+/// JSON Schema of the string representation, to be referenced via
+/// `#[schemars(schema_with = ...)]`.
+///
+/// **REQUIRES FEATURE**: `schemars`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use schemars::{schema_for, JsonSchema};
+///
+/// #[derive(JsonSchema)]
+/// struct Account {
+///     #[schemars(schema_with = "digit_sequence::serde_str::json_schema")]
+///     code: DigitSequence,
+/// }
+///
+/// let schema = schema_for!(Account);
+/// let code = &schema.as_value()["properties"]["code"];
+///
+/// assert_eq!(code["type"], "string");
+/// assert_eq!(code["pattern"], "^[0-9]*$");
+/// ```
+#[cfg(feature = "schemars")]
+pub fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "description": "Sequence of 0-9 digits",
+        "type": "string",
+        "pattern": "^[0-9]*$"
+    })
+}
+
+/// OpenAPI schema of the string representation, to be referenced via
+/// `#[schema(schema_with = ...)]`.
+///
+/// **REQUIRES FEATURE**: `utoipa`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use utoipa::{PartialSchema, ToSchema};
+///
+/// #[derive(ToSchema)]
+/// struct Account {
+///     #[schema(schema_with = digit_sequence::serde_str::openapi_schema)]
+///     code: DigitSequence,
+/// }
+///
+/// # fn main() -> GenericResult<()> {
+/// let schema = serde_json::to_value(Account::schema())?;
+/// let code = &schema["properties"]["code"];
+///
+/// assert_eq!(code["type"], "string");
+/// assert_eq!(code["pattern"], "^[0-9]*$");
+///
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "utoipa")]
+pub fn openapi_schema() -> utoipa::openapi::Object {
+    utoipa::openapi::ObjectBuilder::new()
+        .description(Some("Sequence of 0-9 digits"))
+        .schema_type(utoipa::openapi::schema::Type::String)
+        .pattern(Some("^[0-9]*$"))
+        .build()
+}
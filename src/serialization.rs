@@ -0,0 +1,183 @@
+use crate::{CrateResult, DigitSequence, Digits};
+use alloc::format;
+use core::fmt::Formatter;
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::{Serialize, Serializer};
+
+/// Visitor shared by the default and the [flexible](crate::serde_flexible) deserialization:
+/// strings and numbers are only accepted when the format drives deserialization,
+/// whereas bytes are expected to be [packed](DigitSequence::pack) digits.
+pub(crate) struct DigitSequenceVisitor;
+
+impl<'de> Visitor<'de> for DigitSequenceVisitor {
+    type Value = DigitSequence;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        write!(formatter, "a sequence of 0-9 digits")
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut digits = Digits::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(digit) = seq.next_element::<u8>()? {
+            if digit >= 10 {
+                return Err(A::Error::custom(format!(
+                    "non-digit number {} at index {}",
+                    digit,
+                    digits.len()
+                )));
+            }
+
+            digits.push(digit);
+        }
+
+        Ok(DigitSequence(digits))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        DigitSequence::unpack(value).map_err(E::custom)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(value.into())
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let result: CrateResult<DigitSequence> = value.try_into();
+
+        result.map_err(E::custom)
+    }
+}
+
+/// Serialization depends on the format: human-readable formats - like JSON -
+/// get an array of digits, whereas binary formats get the
+/// [packed](DigitSequence::pack) digits as a byte string, half the size.
+///
+/// Binary data produced by versions earlier than 0.4 - encoding an array
+/// of digits - cannot be deserialized anymore.
+///
+/// **REQUIRES FEATURE**: `serde`.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: DigitSequence = 9786u16.into();
+///
+/// assert_eq!(serde_json::to_string(&sequence)?, "[9,7,8,6]");
+///
+/// let binary = rmp_serde::to_vec(&sequence)?;
+/// assert_eq!(binary, [0xC4, 2, 0x97, 0x86]);
+///
+/// let deserialized: DigitSequence = rmp_serde::from_slice(&binary)?;
+/// assert_eq!(deserialized, sequence);
+///
+/// # Ok(())
+/// # }
+/// ```
+impl Serialize for DigitSequence {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_newtype_struct("DigitSequence", &self.0[..])
+        } else {
+            serializer.serialize_bytes(&self.pack())
+        }
+    }
+}
+
+/// Deserialization validates each item, so that it can only
+/// produce legitimate sequences of 0-9 digits.
+///
+/// **REQUIRES FEATURE**: `serde`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use serde_json::from_str;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: DigitSequence = from_str("[9,7,8,6]")?;
+/// assert_eq!(sequence, [9, 7, 8, 6]);
+///
+/// let result = from_str::<DigitSequence>("[3,12]");
+/// let error = result.unwrap_err().to_string();
+/// assert!(error.starts_with("non-digit number 12 at index 1"));
+///
+/// # Ok(())
+/// # }
+/// ```
+impl<'de> Deserialize<'de> for DigitSequence {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_newtype_struct("DigitSequence", DigitSequenceVisitor)
+        } else {
+            deserializer.deserialize_bytes(DigitSequenceVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use serde_json::from_str;
+    use speculate2::*;
+
+    speculate! {
+        describe "Deserializing" {
+            it "should accept the empty array" {
+                eq!(from_str::<DigitSequence>("[]").unwrap(), []);
+            }
+
+            it "should reject numbers that do not fit a u8" {
+                assert!(from_str::<DigitSequence>("[300]").is_err());
+                assert!(from_str::<DigitSequence>("[-1]").is_err());
+            }
+        }
+
+        describe "Binary round-trip" {
+            it "should preserve odd-length sequences with leading zeros" {
+                let sequence: DigitSequence = "00907".parse().unwrap();
+
+                let binary = rmp_serde::to_vec(&sequence).unwrap();
+                eq!(rmp_serde::from_slice::<DigitSequence>(&binary).unwrap(), sequence);
+            }
+
+            it "should reject invalid packed digits" {
+                let binary = [0xC4, 1, 0xA0];
+                assert!(rmp_serde::from_slice::<DigitSequence>(&binary).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{CrateError, CrateResult, DigitSequence, DigitSlice};
+use alloc::sync::Arc;
+use core::fmt::Display;
+use core::str::FromStr;
+
+/// Immutable sequence of digits whose storage is shared via [Arc] - so that
+/// cloning is O(1) whatever the length: ideal for handing very long sequences
+/// to multiple threads.
+///
+/// All the read-only operations of [DigitSlice] are available via
+/// [as_digit_slice](SharedDigitSequence::as_digit_slice).
+///
+/// ```
+/// use digit_sequence::*;
+/// use std::thread;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: DigitSequence = "79927398713".repeat(1000).parse()?;
+/// let shared = SharedDigitSequence::from(sequence);
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let shared = shared.clone();
+///         thread::spawn(move || shared.iter().filter(|&&digit| digit == 3).count())
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), 2000);
+/// }
+///
+/// let copy = shared.clone();
+/// assert!(copy.shares_storage_with(&shared));
+/// assert_eq!(copy.len(), 11000);
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SharedDigitSequence(Arc<[u8]>);
+
+impl SharedDigitSequence {
+    /// Number of digits in the sequence.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Tells whether the sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the digits.
+    pub fn iter(&self) -> core::slice::Iter<'_, u8> {
+        self.0.iter()
+    }
+
+    /// Borrows the digits as a [DigitSlice].
+    pub fn as_digit_slice(&self) -> DigitSlice<'_> {
+        DigitSlice(&self.0)
+    }
+
+    /// Tells whether the two sequences are clones of each other - that is,
+    /// whether they point to the very same storage.
+    pub fn shares_storage_with(&self, other: &SharedDigitSequence) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<'a> IntoIterator for &'a SharedDigitSequence {
+    type Item = &'a u8;
+
+    type IntoIter = core::slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Conversion from a [DigitSequence] copies the digits - just once - into shared storage.
+impl From<DigitSequence> for SharedDigitSequence {
+    fn from(sequence: DigitSequence) -> SharedDigitSequence {
+        (&sequence).into()
+    }
+}
+
+/// Conversion from a &[DigitSequence] copies the digits into shared storage.
+impl From<&DigitSequence> for SharedDigitSequence {
+    fn from(sequence: &DigitSequence) -> SharedDigitSequence {
+        SharedDigitSequence(Arc::from(&sequence.0[..]))
+    }
+}
+
+/// Conversion to [DigitSequence] copies the digits - as it produces
+/// a sequence that is independent of the other clones.
+impl From<&SharedDigitSequence> for DigitSequence {
+    fn from(sequence: &SharedDigitSequence) -> DigitSequence {
+        sequence.as_digit_slice().into()
+    }
+}
+
+/// Conversion to [DigitSequence] copies the digits - as it produces
+/// a sequence that is independent of the other clones.
+impl From<SharedDigitSequence> for DigitSequence {
+    fn from(sequence: SharedDigitSequence) -> DigitSequence {
+        (&sequence).into()
+    }
+}
+
+/// A slice of numbers can be converted as long as its values
+/// are 0-9 digits - otherwise, the result is a [CrateError::NonDigitNumber].
+impl TryFrom<&[u8]> for SharedDigitSequence {
+    type Error = CrateError;
+
+    fn try_from(digits: &[u8]) -> CrateResult<Self> {
+        let slice: DigitSlice = digits.try_into()?;
+
+        Ok(SharedDigitSequence(Arc::from(slice.as_slice())))
+    }
+}
+
+/// Parsing works just like [DigitSequence].
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: SharedDigitSequence = "0392".parse()?;
+/// assert_eq!(sequence, [0, 3, 9, 2]);
+///
+/// let result: CrateResult<SharedDigitSequence> = "03x".parse();
+/// assert_eq!(result, Err(CrateError::NonDigitChar('x')));
+///
+/// # Ok(())
+/// # }
+/// ```
+impl FromStr for SharedDigitSequence {
+    type Err = CrateError;
+
+    fn from_str(s: &str) -> CrateResult<Self> {
+        let sequence: DigitSequence = s.parse()?;
+
+        Ok(sequence.into())
+    }
+}
+
+/// The string representation is the same as [DigitSequence].
+impl Display for SharedDigitSequence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_digit_slice().fmt(f)
+    }
+}
+
+/// [SharedDigitSequence] can be compared with an array of [u8].
+impl<const N: usize> PartialEq<[u8; N]> for SharedDigitSequence {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.0[..] == other[..]
+    }
+}
+
+/// [SharedDigitSequence] can be compared with a [DigitSequence].
+impl PartialEq<DigitSequence> for SharedDigitSequence {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        self.0[..] == other.0[..]
+    }
+}
+
+/// [DigitSequence] can be compared with a [SharedDigitSequence].
+impl PartialEq<SharedDigitSequence> for DigitSequence {
+    fn eq(&self, other: &SharedDigitSequence) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Shared digit sequence" {
+            it "should share the storage between clones" {
+                let shared: SharedDigitSequence = "907".parse().unwrap();
+                let clone = shared.clone();
+
+                assert!(clone.shares_storage_with(&shared));
+                eq!(clone, shared);
+            }
+
+            it "should not share the storage between equal sequences created independently" {
+                let left: SharedDigitSequence = "907".parse().unwrap();
+                let right: SharedDigitSequence = "907".parse().unwrap();
+
+                assert!(!left.shares_storage_with(&right));
+                eq!(left, right);
+            }
+
+            it "should round-trip via DigitSequence" {
+                let sequence: DigitSequence = 1234567u32.into();
+                let shared = SharedDigitSequence::from(&sequence);
+
+                eq!(shared.to_string(), sequence.to_string());
+                eq!(DigitSequence::from(shared), sequence);
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{CrateError, CrateResult, DigitSequence};
+use crate::{CrateError, CrateResult, DigitSequence, Digits};
 
 /// A slice of [u8] numbers can be converted to [DigitSequence]
 /// as long as its values are 0-9 digits; the empty slice is
@@ -43,7 +43,7 @@ impl TryFrom<&[u8]> for DigitSequence {
     type Error = CrateError;
 
     fn try_from(digits: &[u8]) -> CrateResult<Self> {
-        let mut digits_vec = vec![];
+        let mut digits_vec = Digits::with_capacity(digits.len());
 
         for &digit in digits {
             if digit >= 10 {
@@ -79,6 +79,74 @@ impl TryFrom<&[u8]> for DigitSequence {
 /// ```
 impl PartialEq<&[u8]> for DigitSequence {
     fn eq(&self, other: &&[u8]) -> bool {
-        self.0 == *other
+        self.0[..] == **other
+    }
+}
+
+macro_rules! impl_try_from_unsigned_slice {
+    ($type: ty) => {
+        /// A slice of *unsigned* integers can be converted to [DigitSequence]
+        /// as long as its values are 0-9 digits - otherwise, the result
+        /// is a [CrateError::NonDigitNumber].
+        impl TryFrom<&[$type]> for DigitSequence {
+            type Error = CrateError;
+
+            fn try_from(values: &[$type]) -> CrateResult<Self> {
+                DigitSequence::try_from_iter(values)
+            }
+        }
+    };
+}
+
+macro_rules! impl_try_from_signed_slice {
+    ($type: ty) => {
+        /// A slice of *signed* integers can be converted to [DigitSequence]
+        /// as long as its values are 0-9 digits - otherwise, the result is a
+        /// [CrateError::NegativeNumber] or a [CrateError::NonDigitNumber].
+        impl TryFrom<&[$type]> for DigitSequence {
+            type Error = CrateError;
+
+            fn try_from(values: &[$type]) -> CrateResult<Self> {
+                DigitSequence::try_from_iter(values)
+            }
+        }
+    };
+}
+
+impl_try_from_unsigned_slice!(u16);
+impl_try_from_unsigned_slice!(u32);
+impl_try_from_unsigned_slice!(u64);
+impl_try_from_unsigned_slice!(u128);
+impl_try_from_unsigned_slice!(usize);
+
+impl_try_from_signed_slice!(i8);
+impl_try_from_signed_slice!(i16);
+impl_try_from_signed_slice!(i32);
+impl_try_from_signed_slice!(i64);
+impl_try_from_signed_slice!(i128);
+impl_try_from_signed_slice!(isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Converting a slice of wider integers" {
+            it "should accept 0-9 values" {
+                let values: Vec<u64> = vec![9, 0, 2, 1, 0];
+
+                eq!(DigitSequence::try_from(values.as_slice()), Ok(DigitSequence::from(90210u32)));
+                eq!(DigitSequence::try_from(&[0i32, 7][..]), Ok(crate::digits![0, 7]));
+            }
+
+            it "should report the first invalid value" {
+                eq!(DigitSequence::try_from(&[1u32, 300, 4000][..]), Err(CrateError::NonDigitNumber(300)));
+                eq!(DigitSequence::try_from(&[1i64, -3, 40][..]), Err(CrateError::NegativeNumber(-3)));
+                eq!(DigitSequence::try_from(&[1i8, 30, -4][..]), Err(CrateError::NonDigitNumber(30)));
+                eq!(DigitSequence::try_from(&[u128::MAX][..]), Err(CrateError::NonDigitNumber(u128::MAX)));
+            }
+        }
     }
 }
@@ -0,0 +1,240 @@
+use crate::arithmetic::significant_digits;
+use crate::DigitSequence;
+
+/// Descriptive statistics about the digits of a [DigitSequence],
+/// as returned by [DigitSequence::stats].
+///
+/// **REQUIRES FEATURE**: `std`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigitStatistics {
+    /// Arithmetic mean of the digits.
+    pub mean: f64,
+
+    /// Median of the digits - the mean of the two central digits
+    /// when the sequence has an even length.
+    pub median: f64,
+
+    /// The most frequent digits, in ascending order.
+    pub modes: Vec<u8>,
+
+    /// Shannon entropy of the digit distribution, in bits - ranging
+    /// from 0 (a single repeated digit) to log2(10) (uniform distribution).
+    pub entropy: f64,
+}
+
+#[cfg(feature = "std")]
+impl DigitSequence {
+    /// Descriptive statistics about the digits - or [None] if the sequence is empty.
+    ///
+    /// **REQUIRES FEATURE**: `std`.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = [1, 2, 2, 9].try_into()?;
+    /// let stats = sequence.stats().unwrap();
+    ///
+    /// assert_eq!(stats.mean, 3.5);
+    /// assert_eq!(stats.median, 2.0);
+    /// assert_eq!(stats.modes, vec![2]);
+    /// assert_eq!(stats.entropy, 1.5);
+    ///
+    /// let repeated: DigitSequence = 7777u16.into();
+    /// assert_eq!(repeated.stats().unwrap().entropy, 0.0);
+    ///
+    /// assert_eq!(DigitSequence::new().stats(), None);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats(&self) -> Option<DigitStatistics> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut counts = [0usize; 10];
+
+        for &digit in &self.0 {
+            counts[digit as usize] += 1;
+        }
+
+        let len = self.0.len();
+
+        let mean = self.digit_sum() as f64 / len as f64;
+
+        let median = {
+            let nth_smallest = |index: usize| {
+                let mut seen = 0;
+
+                counts
+                    .iter()
+                    .position(|&count| {
+                        seen += count;
+                        seen > index
+                    })
+                    .unwrap() as f64
+            };
+
+            if len % 2 == 1 {
+                nth_smallest(len / 2)
+            } else {
+                (nth_smallest(len / 2 - 1) + nth_smallest(len / 2)) / 2.0
+            }
+        };
+
+        let max_count = *counts.iter().max().unwrap();
+
+        let modes = (0u8..10)
+            .filter(|&digit| counts[digit as usize] == max_count)
+            .collect();
+
+        let entropy = -counts
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let probability = count as f64 / len as f64;
+                probability * probability.log2()
+            })
+            .sum::<f64>();
+
+        Some(DigitStatistics {
+            mean,
+            median,
+            modes,
+            entropy: entropy.max(0.0),
+        })
+    }
+}
+
+/// Expected frequencies of the leading digits 1-9 according to Benford's law.
+pub const BENFORD_EXPECTED_DISTRIBUTION: [f64; 9] = [
+    core::f64::consts::LOG10_2,
+    0.176_091_259_055_681_24,
+    0.124_938_736_608_299_92,
+    0.096_910_013_008_056_42,
+    0.079_181_246_047_624_82,
+    0.066_946_789_630_613_22,
+    0.057_991_946_977_686_73,
+    0.051_152_522_447_381_29,
+    0.045_757_490_560_675_14,
+];
+
+/// Relative frequencies of the leading *significant* digits (1-9) within
+/// the given sequences - where the item at index `i` refers to digit `i + 1`.
+///
+/// Leading zeros are skipped, and sequences representing 0
+/// (including the empty sequence) are ignored; if no sequence
+/// has a leading significant digit, all the frequencies are 0.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequences: Vec<DigitSequence> = vec![
+///     13u8.into(),
+///     [0, 1, 9].try_into()?,
+///     290u16.into(),
+///     0u8.into(),
+///     DigitSequence::new(),
+/// ];
+///
+/// let distribution = benford_distribution(&sequences);
+///
+/// assert_eq!(distribution[0], 2.0 / 3.0);
+/// assert_eq!(distribution[1], 1.0 / 3.0);
+/// assert!(distribution[2..].iter().all(|&frequency| frequency == 0.0));
+///
+/// # Ok(())
+/// # }
+/// ```
+pub fn benford_distribution<'a, I>(sequences: I) -> [f64; 9]
+where
+    I: IntoIterator<Item = &'a DigitSequence>,
+{
+    let mut counts = [0u64; 9];
+    let mut total = 0u64;
+
+    for sequence in sequences {
+        if let Some(&leading_digit) = significant_digits(&sequence.0).first() {
+            counts[leading_digit as usize - 1] += 1;
+            total += 1;
+        }
+    }
+
+    let mut distribution = [0.0; 9];
+
+    if total > 0 {
+        for (frequency, count) in distribution.iter_mut().zip(counts) {
+            *frequency = count as f64 / total as f64;
+        }
+    }
+
+    distribution
+}
+
+/// Mean absolute deviation between the given leading-digit distribution,
+/// usually returned by [benford_distribution], and
+/// [BENFORD_EXPECTED_DISTRIBUTION]: the closer to 0, the better
+/// the data conform to Benford's law.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let conforming: Vec<DigitSequence> = (0..127)
+///     .map(|exponent| DigitSequence::from(2u128.pow(exponent)))
+///     .collect();
+/// let conforming_score = benford_deviation(&benford_distribution(&conforming));
+///
+/// let suspicious: Vec<DigitSequence> = (500u16..1000).map(DigitSequence::from).collect();
+/// let suspicious_score = benford_deviation(&benford_distribution(&suspicious));
+///
+/// assert!(conforming_score < 0.01);
+/// assert!(suspicious_score > 0.05);
+///
+/// assert_eq!(benford_deviation(&BENFORD_EXPECTED_DISTRIBUTION), 0.0);
+/// ```
+pub fn benford_deviation(distribution: &[f64; 9]) -> f64 {
+    distribution
+        .iter()
+        .zip(BENFORD_EXPECTED_DISTRIBUTION)
+        .map(|(actual, expected)| (actual - expected).abs())
+        .sum::<f64>()
+        / 9.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Computing digit statistics" {
+            it "should compute the median of an odd-length sequence" {
+                let sequence: DigitSequence = [9, 0, 4].try_into().unwrap();
+
+                eq!(sequence.stats().unwrap().median, 4.0);
+            }
+
+            it "should compute the median of an even-length sequence" {
+                let sequence: DigitSequence = [9, 0, 4, 1].try_into().unwrap();
+
+                eq!(sequence.stats().unwrap().median, 2.5);
+            }
+
+            it "should return all the modes" {
+                let sequence: DigitSequence = [9, 0, 0, 4, 9].try_into().unwrap();
+
+                eq!(sequence.stats().unwrap().modes, vec![0, 9]);
+            }
+
+            it "should return the maximum entropy for uniformly distributed digits" {
+                let sequence: DigitSequence = "0123456789".parse().unwrap();
+
+                eq!(sequence.stats().unwrap().entropy, 10f64.log2());
+            }
+        }
+    }
+}
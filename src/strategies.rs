@@ -0,0 +1,87 @@
+//! Strategies for property-based testing via [proptest](https://crates.io/crates/proptest).
+//!
+//! **REQUIRES FEATURE**: `proptest`.
+//!
+//! [DigitSequence] implements [Arbitrary], so it can be generated via [any]:
+//!
+//! ```
+//! use digit_sequence::*;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn roundtrip_via_string(sequence in any::<DigitSequence>()) {
+//!         let parsed: DigitSequence = sequence.to_string().parse().unwrap();
+//!         prop_assert_eq!(parsed, sequence);
+//!     }
+//! }
+//!
+//! roundtrip_via_string();
+//! ```
+//!
+//! [any]: proptest::prelude::any
+
+use crate::vecs::into_digits;
+use crate::DigitSequence;
+use proptest::arbitrary::Arbitrary;
+use proptest::collection::{vec, SizeRange};
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+/// Maximum length of the sequences generated by [Arbitrary].
+const ARBITRARY_MAX_LEN: usize = 32;
+
+/// Strategy generating sequences of uniformly distributed digits,
+/// whose length is within the given range.
+///
+/// ```
+/// use digit_sequence::*;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn has_expected_length(sequence in digit_sequence::strategies::digit_sequence(4..=6)) {
+///         let len = sequence.iter().len();
+///         prop_assert!((4..=6).contains(&len));
+///     }
+/// }
+///
+/// has_expected_length();
+/// ```
+pub fn digit_sequence(len_range: impl Into<SizeRange>) -> impl Strategy<Value = DigitSequence> {
+    vec(0u8..10, len_range).prop_map(|digits| DigitSequence(into_digits(digits)))
+}
+
+/// Strategy generating sequences - from 2 to 19 digits - whose last digit
+/// is the [Luhn](DigitSequence::luhn_check_digit) check digit.
+///
+/// ```
+/// use digit_sequence::*;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn is_luhn_valid(sequence in digit_sequence::strategies::luhn_valid_sequence()) {
+///         prop_assert!(sequence.luhn_is_valid());
+///     }
+/// }
+///
+/// is_luhn_valid();
+/// ```
+pub fn luhn_valid_sequence() -> impl Strategy<Value = DigitSequence> {
+    digit_sequence(1..=18).prop_map(|payload| {
+        let check_digit = payload.luhn_check_digit();
+
+        let mut digits = payload.0;
+        digits.push(check_digit);
+
+        DigitSequence(digits)
+    })
+}
+
+/// [DigitSequence] can be generated by proptest - with up to 32 digits.
+impl Arbitrary for DigitSequence {
+    type Parameters = ();
+
+    type Strategy = BoxedStrategy<DigitSequence>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        digit_sequence(0..=ARBITRARY_MAX_LEN).boxed()
+    }
+}
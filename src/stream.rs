@@ -0,0 +1,315 @@
+use crate::checksum::Checksum;
+use crate::{CrateError, CrateResult, DigitSequence, DigitSlice, Digits};
+use alloc::{vec, vec::Vec};
+use core::borrow::Borrow;
+use core::iter::FusedIterator;
+
+/// Source of digits - possibly unbounded - consumed one at a time,
+/// so that the same algorithms can run on in-memory sequences as well as
+/// on generators and files.
+///
+/// It is implemented by every [Iterator] over [u8] or &[u8] - such as
+/// [DigitSequence::iter], [ChunkedDigitSequence::iter](crate::ChunkedDigitSequence::iter)
+/// or [digits_of](crate::digits_of); the items are validated lazily, so
+/// non-digit values result in a [CrateError::NonDigitNumber].
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::checksum::Luhn;
+///
+/// # fn main() -> GenericResult<()> {
+/// let card_number: DigitSequence = "4111111111111111".parse()?;
+/// assert_eq!(card_number.iter().find_pattern(&"1111".parse::<DigitSequence>()?), Some(1));
+///
+/// let mut repeating = [1, 2, 3].into_iter().cycle();
+/// assert_eq!(repeating.take_sequence(4)?, [1, 2, 3, 1]);
+/// assert_eq!(repeating.take_sequence(2)?, [2, 3]);
+///
+/// let payload = digits_of(7992739871u64);
+/// assert_eq!(payload.checksum_fold(&Luhn)?, 3);
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Files can be streamed, too - for example, once a
+/// [MappedDigitFile](crate::MappedDigitFile) has been validated,
+/// via `file.digits().flatten()`.
+pub trait DigitStream: Iterator<Item: Borrow<u8>> + Sized {
+    /// Consumes up to `len` digits, collecting them into a [DigitSequence] -
+    /// which is shorter only if the stream ends earlier.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let mut stream = digits_of(90210u32);
+    ///
+    /// assert_eq!(stream.take_sequence(3)?, [9, 0, 2]);
+    /// assert_eq!(stream.take_sequence(3)?, [1, 0]);
+    /// assert_eq!(stream.take_sequence(3)?, []);
+    ///
+    /// let mut invalid = [9u8, 10].into_iter();
+    /// assert_eq!(invalid.take_sequence(2), Err(CrateError::NonDigitNumber(10)));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn take_sequence(&mut self, len: usize) -> CrateResult<DigitSequence> {
+        let mut digits = Digits::with_capacity(len.min(self.size_hint().0));
+
+        for item in self.by_ref().take(len) {
+            digits.push(validate(*item.borrow())?);
+        }
+
+        Ok(DigitSequence(digits))
+    }
+
+    /// Consumes the whole stream as a payload, computing its check digit
+    /// via the given [Checksum] - without allocating, whenever the
+    /// algorithm supports [folding](Checksum::fold_check_digit).
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use digit_sequence::checksum::{Damm, Luhn};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(digits_of(572u16).checksum_fold(&Damm)?, 4);
+    /// assert_eq!("49015420323751".parse::<DigitSequence>()?.iter().checksum_fold(&Luhn)?, 8);
+    ///
+    /// let result = [7u8, 12, 9].into_iter().checksum_fold(&Damm);
+    /// assert_eq!(result, Err(CrateError::NonDigitNumber(12)));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn checksum_fold(self, checksum: &impl Checksum) -> CrateResult<u8> {
+        checksum.fold_check_digit(self.map(|item| *item.borrow()))
+    }
+
+    /// Consumes the stream up to the first occurrence of the given pattern,
+    /// returning the index at which it starts - or [None] if the stream ends
+    /// before the pattern appears; the empty pattern is found at index 0.
+    ///
+    /// Only the pattern is kept in memory - so even unbounded streams can be
+    /// searched, although the search never ends if the pattern never appears.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let pattern: DigitSequence = "121".parse()?;
+    ///
+    /// let sequence: DigitSequence = "1121121".parse()?;
+    /// assert_eq!(sequence.iter().find_pattern(&pattern), Some(1));
+    ///
+    /// let powers_of_two = (0..).flat_map(|exponent| digits_of(1u128 << exponent));
+    /// assert_eq!(powers_of_two.find_pattern(&"3264".parse::<DigitSequence>()?), Some(6));
+    ///
+    /// assert_eq!(digits_of(3456u16).find_pattern(&pattern), None);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn find_pattern<'a>(self, pattern: impl Into<DigitSlice<'a>>) -> Option<usize> {
+        let pattern = pattern.into().as_slice();
+
+        if pattern.is_empty() {
+            return Some(0);
+        }
+
+        let failure = failure_table(pattern);
+        let mut matched = 0;
+
+        for (index, item) in self.enumerate() {
+            let digit = *item.borrow();
+
+            while matched > 0 && pattern[matched] != digit {
+                matched = failure[matched - 1];
+            }
+
+            if pattern[matched] == digit {
+                matched += 1;
+
+                if matched == pattern.len() {
+                    return Some(index + 1 - matched);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<I> DigitStream for I where I: Iterator<Item: Borrow<u8>> {}
+
+fn validate(digit: u8) -> CrateResult<u8> {
+    if digit < 10 {
+        Ok(digit)
+    } else {
+        Err(CrateError::NonDigitNumber(digit as u128))
+    }
+}
+
+/// Knuth-Morris-Pratt table: for each prefix of the pattern, the length
+/// of its longest proper prefix that is also a suffix.
+fn failure_table(pattern: &[u8]) -> Vec<usize> {
+    let mut failure = vec![0; pattern.len()];
+    let mut matched = 0;
+
+    for index in 1..pattern.len() {
+        while matched > 0 && pattern[matched] != pattern[index] {
+            matched = failure[matched - 1];
+        }
+
+        if pattern[matched] == pattern[index] {
+            matched += 1;
+        }
+
+        failure[index] = matched;
+    }
+
+    failure
+}
+
+/// Concatenation of the digits of the items - for example, integers or
+/// [DigitSequence] values - into a single digit stream; it is implemented
+/// by every [Iterator] whose items can be converted [Into] [DigitSequence].
+///
+/// ```
+/// use digit_sequence::*;
+/// use digit_sequence::constants::ChampernowneDigits;
+///
+/// # fn main() -> GenericResult<()> {
+/// let integers = (1u32..).concat_digits().take_sequence(15)?;
+/// assert_eq!(integers, ChampernowneDigits::new().take_sequence(15)?);
+///
+/// let squares = (1u32..).map(|n| n * n).concat_digits().take_sequence(12)?;
+/// assert_eq!(squares.to_string(), "149162536496");
+///
+/// let fields: Vec<DigitSequence> = vec!["12".parse()?, "345".parse()?, "6".parse()?];
+/// let joined = DigitSequence::try_from_iter(fields.into_iter().concat_digits().separated_by(0)?)?;
+/// assert_eq!(joined.to_string(), "12034506");
+///
+/// # Ok(())
+/// # }
+/// ```
+pub trait ConcatDigits: Iterator<Item: Into<DigitSequence>> + Sized {
+    /// Flattens the digits of each item into a single stream.
+    fn concat_digits(self) -> ConcatenatedDigits<Self> {
+        ConcatenatedDigits {
+            items: self,
+            current: DigitSequence::new().into_iter(),
+            separator: None,
+            started: false,
+        }
+    }
+}
+
+impl<I> ConcatDigits for I where I: Iterator<Item: Into<DigitSequence>> {}
+
+/// Digit stream returned by [ConcatDigits::concat_digits].
+#[derive(Debug, Clone)]
+pub struct ConcatenatedDigits<I> {
+    items: I,
+    current: <DigitSequence as IntoIterator>::IntoIter,
+    separator: Option<u8>,
+    started: bool,
+}
+
+impl<I> ConcatenatedDigits<I> {
+    /// Inserts the given digit between the digits of consecutive items;
+    /// if it is not a 0-9 digit, the result is a [CrateError::NonDigitNumber].
+    pub fn separated_by(self, separator: u8) -> CrateResult<ConcatenatedDigits<I>> {
+        Ok(ConcatenatedDigits {
+            separator: Some(validate(separator)?),
+            ..self
+        })
+    }
+}
+
+impl<I> Iterator for ConcatenatedDigits<I>
+where
+    I: Iterator<Item: Into<DigitSequence>>,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        loop {
+            if let Some(digit) = self.current.next() {
+                return Some(digit);
+            }
+
+            let item = self.items.next()?;
+            self.current = item.into().into_iter();
+
+            let separator = if self.started { self.separator } else { None };
+            self.started = true;
+
+            if separator.is_some() {
+                return separator;
+            }
+        }
+    }
+}
+
+impl<I> FusedIterator for ConcatenatedDigits<I> where I: FusedIterator<Item: Into<DigitSequence>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Finding a pattern in a stream" {
+            it "should agree with a naive search" {
+                let haystack: DigitSequence = "0010010001001001000100".parse().unwrap();
+
+                for pattern in ["0", "1", "00", "0001", "10010001", "1001001000", "111"] {
+                    let pattern: DigitSequence = pattern.parse().unwrap();
+
+                    let expected = haystack
+                        .0
+                        .windows(pattern.0.len())
+                        .position(|window| window == &pattern.0[..]);
+
+                    eq!(haystack.iter().find_pattern(&pattern), expected);
+                }
+            }
+
+            it "should not find a pattern longer than the stream" {
+                let pattern: DigitSequence = "123".parse().unwrap();
+
+                eq!(crate::digits_of(12u8).find_pattern(&pattern), None);
+            }
+        }
+
+        describe "Concatenating digit sources" {
+            it "should separate the items, even when empty" {
+                let items = vec![DigitSequence::new(), 7u8.into(), DigitSequence::new()];
+                let joined = DigitSequence::try_from_iter(items.into_iter().concat_digits().separated_by(9).unwrap()).unwrap();
+
+                eq!(joined, [9, 7, 9]);
+            }
+
+            it "should reject non-digit separators" {
+                let result = [1u8, 2].into_iter().concat_digits().separated_by(10);
+
+                eq!(result.map(|digits| digits.count()), Err(CrateError::NonDigitNumber(10)));
+            }
+
+            it "should yield nothing for no items" {
+                eq!(core::iter::empty::<u8>().concat_digits().separated_by(0).unwrap().next(), None);
+            }
+        }
+
+        describe "Folding a checksum over a stream" {
+            it "should report the first non-digit" {
+                let result = [1u8, 2, 30, 40].iter().checksum_fold(&crate::checksum::Luhn);
+
+                eq!(result, Err(CrateError::NonDigitNumber(30)));
+            }
+        }
+    }
+}
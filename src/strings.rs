@@ -1,5 +1,87 @@
-use crate::{CrateError, CrateResult, DigitSequence};
-use std::{fmt::Display, str::FromStr};
+use crate::{CrateError, CrateResult, DigitSequence, Digits};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt::Display, ops::Range, str::FromStr};
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// Size of the stack buffers used to write ASCII digits - for example,
+/// by [DigitSequence::write_ascii] and by [Display].
+pub(crate) const ASCII_WRITE_BUFFER_SIZE: usize = 256;
+
+/// The zero of every run of Unicode `Nd` (decimal digit) characters, as of Unicode 15.
+const UNICODE_ZERO_DIGITS: [char; 68] = [
+    '\u{0030}',
+    '\u{0660}',
+    '\u{06F0}',
+    '\u{07C0}',
+    '\u{0966}',
+    '\u{09E6}',
+    '\u{0A66}',
+    '\u{0AE6}',
+    '\u{0B66}',
+    '\u{0BE6}',
+    '\u{0C66}',
+    '\u{0CE6}',
+    '\u{0D66}',
+    '\u{0DE6}',
+    '\u{0E50}',
+    '\u{0ED0}',
+    '\u{0F20}',
+    '\u{1040}',
+    '\u{1090}',
+    '\u{17E0}',
+    '\u{1810}',
+    '\u{1946}',
+    '\u{19D0}',
+    '\u{1A80}',
+    '\u{1A90}',
+    '\u{1B50}',
+    '\u{1BB0}',
+    '\u{1C40}',
+    '\u{1C50}',
+    '\u{A620}',
+    '\u{A8D0}',
+    '\u{A900}',
+    '\u{A9D0}',
+    '\u{A9F0}',
+    '\u{AA50}',
+    '\u{ABF0}',
+    '\u{FF10}',
+    '\u{104A0}',
+    '\u{10D30}',
+    '\u{11066}',
+    '\u{110F0}',
+    '\u{11136}',
+    '\u{111D0}',
+    '\u{112F0}',
+    '\u{11450}',
+    '\u{114D0}',
+    '\u{11650}',
+    '\u{116C0}',
+    '\u{11730}',
+    '\u{118E0}',
+    '\u{11950}',
+    '\u{11C50}',
+    '\u{11D50}',
+    '\u{11DA0}',
+    '\u{11F50}',
+    '\u{16A60}',
+    '\u{16AC0}',
+    '\u{16B50}',
+    '\u{1D7CE}',
+    '\u{1D7D8}',
+    '\u{1D7E2}',
+    '\u{1D7EC}',
+    '\u{1D7F6}',
+    '\u{1E140}',
+    '\u{1E2F0}',
+    '\u{1E4F0}',
+    '\u{1E950}',
+    '\u{1FBF0}',
+];
 
 /// The string representation of a [DigitSequence] is just the concatenation of its digits.
 ///
@@ -15,14 +97,68 @@ use std::{fmt::Display, str::FromStr};
 /// let digit_sequence: DigitSequence = 175438u32.into();
 /// assert_eq!(digit_sequence.to_string(), "175438");
 /// ```
+///
+/// The standard formatting flags - width, fill, alignment and zero-padding - are supported:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let digit_sequence: DigitSequence = 387u16.into();
+///
+/// assert_eq!(format!("{:>6}", digit_sequence), "   387");
+/// assert_eq!(format!("{:<6}|", digit_sequence), "387   |");
+/// assert_eq!(format!("{:*^7}", digit_sequence), "**387**");
+/// assert_eq!(format!("{:06}", digit_sequence), "000387");
+/// assert_eq!(format!("{:2}", digit_sequence), "387");
+/// ```
+///
+/// Finally, the alternate form (`{:#}`) groups the digits in threes,
+/// from the right, separated by spaces:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let digit_sequence: DigitSequence = 1234567u32.into();
+///
+/// assert_eq!(format!("{:#}", digit_sequence), "1 234 567");
+/// assert_eq!(format!("{:>#10}", digit_sequence), " 1 234 567");
+/// assert_eq!(format!("{}", digit_sequence), "1234567");
+/// ```
 impl Display for DigitSequence {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for &digit in &self.0 {
-            write!(f, "{}", digit)?;
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_digit_slice().fmt(f)
+    }
+}
+
+/// Length of the blocks validated without branching - so that
+/// the compiler can vectorize the check.
+const VALIDATION_BLOCK_LEN: usize = 64;
+
+/// Converts ASCII digits to their values - or returns the index
+/// of the first non-digit byte.
+pub(crate) fn ascii_to_digits(bytes: &[u8]) -> Result<Digits, usize> {
+    for (block_index, block) in bytes.chunks(VALIDATION_BLOCK_LEN).enumerate() {
+        let has_non_digit = block
+            .iter()
+            .fold(false, |found, &byte| found | (byte.wrapping_sub(b'0') > 9));
+
+        if has_non_digit {
+            let offset = block
+                .iter()
+                .position(|byte| !byte.is_ascii_digit())
+                .unwrap();
+
+            return Err(block_index * VALIDATION_BLOCK_LEN + offset);
         }
+    }
 
-        Ok(())
+    let mut digits = Digits::from(bytes);
+
+    for byte in digits.iter_mut() {
+        *byte -= b'0';
     }
+
+    Ok(digits)
 }
 
 /// Parsing a &[str] or [String] works if it only consists of base-10 digits,
@@ -75,15 +211,718 @@ impl FromStr for DigitSequence {
     type Err = CrateError;
 
     fn from_str(s: &str) -> CrateResult<Self> {
-        let mut digits: Vec<u8> = Vec::new();
+        // The bytes preceding the first invalid one are ASCII digits,
+        // so it always starts a char
+        ascii_to_digits(s.as_bytes())
+            .map(DigitSequence)
+            .map_err(|invalid_index| {
+                let invalid_char = s[invalid_index..].chars().next().unwrap();
+                CrateError::NonDigitChar(invalid_char)
+            })
+    }
+}
+
+/// Conversion from &[str] is equivalent to [FromStr], making
+/// string slices usable wherever a [TryFrom] bound is required:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+///
+/// let sequence: DigitSequence = "0302".try_into()?;
+/// assert_eq!(sequence, [0, 3, 0, 2]);
+///
+/// let result: CrateResult<DigitSequence> = "90xy".try_into();
+/// assert_eq!(result, Err(CrateError::NonDigitChar('x')));
+///
+/// # Ok(())
+/// # }
+/// ```
+impl TryFrom<&str> for DigitSequence {
+    type Error = CrateError;
+
+    fn try_from(value: &str) -> CrateResult<Self> {
+        value.parse()
+    }
+}
+
+/// Conversion from [String] is equivalent to [FromStr]:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+///
+/// let sequence: DigitSequence = String::from("0302").try_into()?;
+/// assert_eq!(sequence, [0, 3, 0, 2]);
+///
+/// let result: CrateResult<DigitSequence> = String::from("-90").try_into();
+/// assert_eq!(result, Err(CrateError::NonDigitChar('-')));
+///
+/// # Ok(())
+/// # }
+/// ```
+impl TryFrom<String> for DigitSequence {
+    type Error = CrateError;
+
+    fn try_from(value: String) -> CrateResult<Self> {
+        value.parse()
+    }
+}
+
+/// Conversion from &[String] is equivalent to [FromStr]:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+///
+/// let source = String::from("0302");
+/// let sequence: DigitSequence = (&source).try_into()?;
+/// assert_eq!(sequence, [0, 3, 0, 2]);
+///
+/// let source = String::from(" 90");
+/// let result: CrateResult<DigitSequence> = (&source).try_into();
+/// assert_eq!(result, Err(CrateError::NonDigitChar(' ')));
+///
+/// # Ok(())
+/// # }
+/// ```
+impl TryFrom<&String> for DigitSequence {
+    type Error = CrateError;
+
+    fn try_from(value: &String) -> CrateResult<Self> {
+        value.parse()
+    }
+}
+
+/// Tells whether the string consists of exactly the ASCII representations of the digits.
+fn eq_ascii(digits: &[u8], s: &str) -> bool {
+    digits.len() == s.len()
+        && digits
+            .iter()
+            .zip(s.bytes())
+            .all(|(&digit, byte)| digit + b'0' == byte)
+}
+
+/// [DigitSequence] supports equality with &[str] - comparing it with
+/// the concatenation of the digits, so leading zeros matter:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: DigitSequence = "0392".parse()?;
+///
+/// assert_eq!(sequence, "0392");
+/// assert_eq!("0392", sequence);
+///
+/// assert_ne!(sequence, "392");
+/// assert_ne!(sequence, "03920");
+/// assert_ne!(sequence, "0 392");
+///
+/// assert_eq!(DigitSequence::new(), "");
+///
+/// # Ok(())
+/// # }
+/// ```
+impl PartialEq<&str> for DigitSequence {
+    fn eq(&self, other: &&str) -> bool {
+        eq_ascii(&self.0, other)
+    }
+}
+
+impl PartialEq<str> for DigitSequence {
+    fn eq(&self, other: &str) -> bool {
+        eq_ascii(&self.0, other)
+    }
+}
+
+impl PartialEq<DigitSequence> for &str {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        eq_ascii(&other.0, self)
+    }
+}
+
+impl PartialEq<DigitSequence> for str {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        eq_ascii(&other.0, self)
+    }
+}
+
+/// [DigitSequence] supports equality with [String], just like &[str]:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let sequence: DigitSequence = 90210u32.into();
+///
+/// assert_eq!(sequence, String::from("90210"));
+/// assert_eq!(String::from("90210"), sequence);
+///
+/// assert_ne!(sequence, String::from("090210"));
+///
+/// # Ok(())
+/// # }
+/// ```
+impl PartialEq<String> for DigitSequence {
+    fn eq(&self, other: &String) -> bool {
+        eq_ascii(&self.0, other)
+    }
+}
+
+impl PartialEq<DigitSequence> for String {
+    fn eq(&self, other: &DigitSequence) -> bool {
+        eq_ascii(&other.0, self)
+    }
+}
+
+/// Span of a string that could not be parsed as digits,
+/// as reported by [DigitSequence::parse_partial].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseIssue {
+    /// The byte range of the skipped characters within the parsed string.
+    pub span: Range<usize>,
+
+    /// The skipped characters.
+    pub content: String,
+}
+
+/// [ParseIssue] has a string representation, describing the skipped characters.
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// let (_, issues) = DigitSequence::parse_partial("12ab3");
+///
+/// assert_eq!(issues[0].to_string(), "Non-digit chars at 2..4: ab");
+/// ```
+impl Display for ParseIssue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Non-digit chars at {}..{}: {}",
+            self.span.start, self.span.end, self.content
+        )
+    }
+}
+
+impl core::error::Error for ParseIssue {}
+
+/// With the `miette` feature, [ParseIssue] is a [miette::Diagnostic],
+/// labeling its span within the parsed string.
+///
+/// **REQUIRES FEATURE**: `miette`.
+///
+/// ```
+/// use digit_sequence::*;
+/// use miette::Diagnostic;
+///
+/// let (_, issues) = DigitSequence::parse_partial("12ab3");
+/// let label = issues[0].labels().unwrap().next().unwrap();
+///
+/// assert_eq!(issues[0].code().unwrap().to_string(), "digit_sequence::parse_issue");
+/// assert_eq!(label.offset(), 2);
+/// assert_eq!(label.len(), 2);
+/// ```
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for ParseIssue {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new("digit_sequence::parse_issue"))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new("only the 0-9 characters are allowed"))
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        Some(Box::new(core::iter::once(miette::LabeledSpan::at(
+            self.span.clone(),
+            "not a digit",
+        ))))
+    }
+}
+
+/// Maps any Unicode decimal digit (general category `Nd`) to its 0-9 value.
+fn unicode_digit_value(current_char: char) -> Option<u8> {
+    let zero_index = UNICODE_ZERO_DIGITS
+        .partition_point(|&zero| zero <= current_char)
+        .checked_sub(1)?;
+
+    let offset = current_char as u32 - UNICODE_ZERO_DIGITS[zero_index] as u32;
+
+    (offset < 10).then_some(offset as u8)
+}
+
+impl DigitSequence {
+    /// Parses a string made of *any* Unicode decimal digits - not just ASCII:
+    /// for example, Arabic-Indic, Devanagari or fullwidth digits;
+    /// scripts can even be mixed within the same string.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(DigitSequence::parse_unicode("٣٤٥")?, [3, 4, 5]);
+    /// assert_eq!(DigitSequence::parse_unicode("१२३")?, [1, 2, 3]);
+    /// assert_eq!(DigitSequence::parse_unicode("１２３")?, [1, 2, 3]);
+    /// assert_eq!(DigitSequence::parse_unicode("0١2")?, [0, 1, 2]);
+    /// assert_eq!(DigitSequence::parse_unicode("")?, []);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Any other character results in a [CrateError::NonDigitChar] - including
+    /// numeric characters that are not decimal digits, such as Roman numerals:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// assert_eq!(DigitSequence::parse_unicode("1x"), Err(CrateError::NonDigitChar('x')));
+    /// assert_eq!(DigitSequence::parse_unicode("Ⅻ"), Err(CrateError::NonDigitChar('Ⅻ')));
+    /// ```
+    pub fn parse_unicode(s: &str) -> CrateResult<DigitSequence> {
+        s.chars()
+            .map(|current_char| {
+                unicode_digit_value(current_char).ok_or(CrateError::NonDigitChar(current_char))
+            })
+            .collect::<CrateResult<Digits>>()
+            .map(DigitSequence)
+    }
+
+    /// Extracts all the ASCII digits from the given string, silently
+    /// skipping any other character - which is convenient for formatted input.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// assert_eq!(DigitSequence::parse_lossy("(555) 123-4567"), [5, 5, 5, 1, 2, 3, 4, 5, 6, 7]);
+    /// assert_eq!(DigitSequence::parse_lossy("4111 1111"), [4, 1, 1, 1, 1, 1, 1, 1]);
+    /// assert_eq!(DigitSequence::parse_lossy("no digits"), []);
+    /// ```
+    pub fn parse_lossy(s: &str) -> DigitSequence {
+        DigitSequence(
+            s.bytes()
+                .filter(u8::is_ascii_digit)
+                .map(|digit| digit - b'0')
+                .collect(),
+        )
+    }
+
+    /// Parses a string made of ASCII digits, ignoring only the given separators;
+    /// any other character still results in a [CrateError::NonDigitChar].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let separators = [' ', '-', '_'];
+    ///
+    /// let sequence = DigitSequence::parse_with_separators("1234-5678", &separators)?;
+    /// assert_eq!(sequence, [1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// let sequence = DigitSequence::parse_with_separators("12 34_5", &separators)?;
+    /// assert_eq!(sequence, [1, 2, 3, 4, 5]);
+    ///
+    /// let result = DigitSequence::parse_with_separators("12a34", &separators);
+    /// assert_eq!(result, Err(CrateError::NonDigitChar('a')));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_with_separators(s: &str, separators: &[char]) -> CrateResult<DigitSequence> {
+        let mut digits = Digits::with_capacity(s.len());
 
         for current_char in s.chars() {
-            match current_char.to_digit(10) {
-                Some(digit) => digits.push(digit as u8),
-                None => return Err(CrateError::NonDigitChar(current_char)),
+            if separators.contains(&current_char) {
+                continue;
+            }
+
+            match current_char {
+                '0'..='9' => digits.push(current_char as u8 - b'0'),
+                _ => return Err(CrateError::NonDigitChar(current_char)),
             }
         }
 
         Ok(DigitSequence(digits))
     }
+
+    /// Tolerant parsing, returning the ASCII digits that could be read
+    /// along with a [ParseIssue] for each run of consecutive non-digit characters -
+    /// so that problems can be logged instead of failing the whole input.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let (sequence, issues) = DigitSequence::parse_partial("12ab3-4");
+    ///
+    /// assert_eq!(sequence, [1, 2, 3, 4]);
+    /// assert_eq!(
+    ///     issues,
+    ///     vec![
+    ///         ParseIssue { span: 2..4, content: "ab".to_string() },
+    ///         ParseIssue { span: 5..6, content: "-".to_string() },
+    ///     ]
+    /// );
+    ///
+    /// let (sequence, issues) = DigitSequence::parse_partial("0392");
+    /// assert_eq!(sequence, [0, 3, 9, 2]);
+    /// assert!(issues.is_empty());
+    /// ```
+    pub fn parse_partial(s: &str) -> (DigitSequence, Vec<ParseIssue>) {
+        let mut digits = Digits::with_capacity(s.len());
+        let mut issues = Vec::new();
+        let mut issue_start: Option<usize> = None;
+
+        let mut close_issue = |issue_start: &mut Option<usize>, end: usize| {
+            if let Some(start) = issue_start.take() {
+                issues.push(ParseIssue {
+                    span: start..end,
+                    content: s[start..end].to_string(),
+                });
+            }
+        };
+
+        for (offset, current_char) in s.char_indices() {
+            match current_char {
+                '0'..='9' => {
+                    close_issue(&mut issue_start, offset);
+                    digits.push(current_char as u8 - b'0');
+                }
+                _ => {
+                    issue_start.get_or_insert(offset);
+                }
+            }
+        }
+
+        close_issue(&mut issue_start, s.len());
+
+        (DigitSequence(digits), issues)
+    }
+
+    /// Parses a byte string made of ASCII digits - such as `b"0123"` - without
+    /// requiring UTF-8 validation, which is handy for data read from sockets or files.
+    ///
+    /// Please, note the difference from the [TryFrom] conversion of &[[u8]],
+    /// whose bytes are the digit *values* themselves.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert_eq!(DigitSequence::from_ascii(b"0392")?, [0, 3, 9, 2]);
+    /// assert_eq!(DigitSequence::from_ascii(b"")?, []);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Any other byte results in a [CrateError::NonDigitChar]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// assert_eq!(DigitSequence::from_ascii(b"90x"), Err(CrateError::NonDigitChar('x')));
+    /// assert_eq!(DigitSequence::from_ascii(&[3]), Err(CrateError::NonDigitChar('\u{3}')));
+    /// ```
+    pub fn from_ascii(bytes: &[u8]) -> CrateResult<DigitSequence> {
+        ascii_to_digits(bytes)
+            .map(DigitSequence)
+            .map_err(|invalid_index| CrateError::NonDigitChar(char::from(bytes[invalid_index])))
+    }
+
+    /// Returns the ASCII bytes (`b'0'..=b'9'`) of the digits, without
+    /// creating an intermediate [String].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let sequence: DigitSequence = 3092u16.into();
+    /// assert_eq!(sequence.to_ascii_vec(), b"3092");
+    ///
+    /// assert_eq!(DigitSequence::new().to_ascii_vec(), b"");
+    /// ```
+    pub fn to_ascii_vec(&self) -> Vec<u8> {
+        self.0.iter().map(|&digit| b'0' + digit).collect()
+    }
+
+    /// Writes the ASCII bytes (`b'0'..=b'9'`) of the digits to the given writer -
+    /// via a small stack buffer, with no heap allocation.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 3092u16.into();
+    /// let mut output: Vec<u8> = b"PIN:".to_vec();
+    ///
+    /// sequence.write_ascii(&mut output)?;
+    /// assert_eq!(output, b"PIN:3092");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_ascii(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        let mut buffer = [0u8; ASCII_WRITE_BUFFER_SIZE];
+
+        for chunk in self.0.chunks(ASCII_WRITE_BUFFER_SIZE) {
+            for (target, &digit) in buffer.iter_mut().zip(chunk) {
+                *target = b'0' + digit;
+            }
+
+            writer.write_all(&buffer[..chunk.len()])?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the ASCII digits to the given writer according to the given [WriteLayout] -
+    /// via a small stack buffer, so that even huge sequences can be dumped
+    /// without creating an intermediate [String].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = "31415926535897932384".parse()?;
+    ///
+    /// let layout = WriteLayout {
+    ///     group_size: Some(5),
+    ///     line_len: Some(10),
+    ///     ..WriteLayout::default()
+    /// };
+    ///
+    /// let mut output: Vec<u8> = vec![];
+    /// sequence.write_to(&mut output, layout)?;
+    /// assert_eq!(output, b"31415 92653\n58979 32384");
+    ///
+    /// let mut output: Vec<u8> = vec![];
+    /// sequence.write_to(&mut output, WriteLayout::default())?;
+    /// assert_eq!(output, b"31415926535897932384");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to(&self, writer: &mut impl Write, layout: WriteLayout) -> std::io::Result<()> {
+        let mut buffer = [0u8; ASCII_WRITE_BUFFER_SIZE];
+        let mut buffer_len = 0;
+
+        let mut separator_bytes = [0u8; 4];
+        let separator = layout
+            .group_separator
+            .encode_utf8(&mut separator_bytes)
+            .as_bytes();
+
+        let is_boundary = |size: Option<usize>, index: usize| {
+            size.is_some_and(|size| size > 0 && index.is_multiple_of(size))
+        };
+
+        let line_len = layout.line_len.filter(|&line_len| line_len > 0);
+
+        for (index, &digit) in self.0.iter().enumerate() {
+            if buffer_len + separator.len() + 1 > ASCII_WRITE_BUFFER_SIZE {
+                writer.write_all(&buffer[..buffer_len])?;
+                buffer_len = 0;
+            }
+
+            let line_index = line_len.map_or(index, |line_len| index % line_len);
+
+            if index > 0 && line_index == 0 {
+                buffer[buffer_len] = b'\n';
+                buffer_len += 1;
+            } else if line_index > 0 && is_boundary(layout.group_size, line_index) {
+                buffer[buffer_len..buffer_len + separator.len()].copy_from_slice(separator);
+                buffer_len += separator.len();
+            }
+
+            buffer[buffer_len] = b'0' + digit;
+            buffer_len += 1;
+        }
+
+        writer.write_all(&buffer[..buffer_len])
+    }
+}
+
+/// Layout of the digits written by [DigitSequence::write_to]; the
+/// [Default] layout just concatenates the digits.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteLayout {
+    /// Number of digits per group - counted from the start of each line.
+    pub group_size: Option<usize>,
+
+    /// Separator written between groups.
+    pub group_separator: char,
+
+    /// Number of digits per line - replacing the group separator with a newline.
+    pub line_len: Option<usize>,
+}
+
+#[cfg(feature = "std")]
+impl Default for WriteLayout {
+    fn default() -> Self {
+        WriteLayout {
+            group_size: None,
+            group_separator: ' ',
+            line_len: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Writing with a layout" {
+            fn write(source: &str, layout: WriteLayout) -> String {
+                let sequence: DigitSequence = source.parse().unwrap();
+                let mut output: Vec<u8> = vec![];
+
+                sequence.write_to(&mut output, layout).unwrap();
+
+                String::from_utf8(output).unwrap()
+            }
+
+            it "should wrap lines without grouping" {
+                let layout = WriteLayout { line_len: Some(3), ..WriteLayout::default() };
+
+                eq!(write("1234567", layout), "123\n456\n7");
+                eq!(write("123456", layout), "123\n456");
+                eq!(write("", layout), "");
+            }
+
+            it "should restart the groups on each line" {
+                let layout = WriteLayout { group_size: Some(2), line_len: Some(3), ..WriteLayout::default() };
+
+                eq!(write("1234567", layout), "12 3\n45 6\n7");
+            }
+
+            it "should support multi-byte separators" {
+                let layout = WriteLayout { group_size: Some(2), group_separator: '·', ..WriteLayout::default() };
+
+                eq!(write("12345", layout), "12·34·5");
+            }
+
+            it "should span several buffers" {
+                let source = "9081726354".repeat(100);
+                let layout = WriteLayout { group_size: Some(1), group_separator: '·', line_len: Some(7) };
+
+                let expected: Vec<String> = source
+                    .as_bytes()
+                    .chunks(7)
+                    .map(|line| line.iter().map(|&byte| char::from(byte).to_string()).collect::<Vec<_>>().join("·"))
+                    .collect();
+
+                eq!(write(&source, layout), expected.join("\n"));
+            }
+        }
+
+        describe "Converting strings via TryFrom" {
+            fn convert<T: TryInto<DigitSequence, Error = CrateError>>(value: T) -> CrateResult<DigitSequence> {
+                value.try_into()
+            }
+
+            it "should be usable within generic code" {
+                eq!(convert("908").unwrap(), [9, 0, 8]);
+                eq!(convert(String::from("908")).unwrap(), [9, 0, 8]);
+
+                let source = String::from("908");
+                eq!(convert(&source).unwrap(), [9, 0, 8]);
+                eq!(source, "908");
+            }
+        }
+
+        describe "Parsing long strings" {
+            it "should report the first non-digit in any block" {
+                for invalid_index in [0, 63, 64, 65, 127, 199] {
+                    let mut source = "9".repeat(200);
+                    source.replace_range(invalid_index..=invalid_index, "x");
+                    source.push('y');
+
+                    eq!(source.parse::<DigitSequence>(), Err(CrateError::NonDigitChar('x')));
+                }
+            }
+
+            it "should report a multi-byte char as a whole" {
+                let source = format!("{}€", "1".repeat(70));
+
+                eq!(source.parse::<DigitSequence>(), Err(CrateError::NonDigitChar('€')));
+            }
+
+            it "should convert every digit" {
+                let source = "0123456789".repeat(13);
+                let sequence: DigitSequence = source.parse().unwrap();
+
+                eq!(sequence.to_string(), source);
+            }
+        }
+
+        describe "Parsing partially" {
+            it "should report a trailing issue" {
+                let (sequence, issues) = DigitSequence::parse_partial("9€");
+
+                eq!(sequence, [9]);
+                eq!(issues, vec![ParseIssue { span: 1..4, content: "€".to_string() }]);
+            }
+
+            it "should report an input without digits as a single issue" {
+                let (sequence, issues) = DigitSequence::parse_partial("abc");
+
+                eq!(sequence, []);
+                eq!(issues, vec![ParseIssue { span: 0..3, content: "abc".to_string() }]);
+            }
+        }
+
+        describe "Parsing ASCII bytes" {
+            it "should report a non-ASCII byte" {
+                eq!(DigitSequence::from_ascii(&[b'7', 0xFF]), Err(CrateError::NonDigitChar('\u{FF}')));
+            }
+        }
+
+        describe "Writing ASCII bytes" {
+            it "should write sequences longer than the buffer" {
+                let sequence = DigitSequence::parse_lossy(&"0123456789".repeat(60));
+                let mut output = Vec::new();
+
+                sequence.write_ascii(&mut output).unwrap();
+
+                eq!(output, sequence.to_ascii_vec());
+                eq!(output.len(), 600);
+            }
+        }
+
+        describe "Mapping Unicode digits" {
+            it "should map every decimal digit of every script" {
+                for &zero in UNICODE_ZERO_DIGITS.iter() {
+                    for value in 0..10u8 {
+                        let current_char = char::from_u32(zero as u32 + value as u32).unwrap();
+
+                        eq!(unicode_digit_value(current_char), Some(value));
+                        assert!(current_char.is_numeric());
+                    }
+                }
+            }
+
+            it "should reject the characters around the digit runs" {
+                for &zero in UNICODE_ZERO_DIGITS.iter() {
+                    let after_nine = char::from_u32(zero as u32 + 10).unwrap();
+
+                    if !UNICODE_ZERO_DIGITS.contains(&after_nine) {
+                        eq!(unicode_digit_value(after_nine), None);
+                    }
+                }
+
+                eq!(unicode_digit_value('/'), None);
+                eq!(unicode_digit_value('\0'), None);
+            }
+        }
+    }
 }
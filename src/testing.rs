@@ -0,0 +1,140 @@
+//! Helpers for testing code built on [DigitSequence] - round-trip assertions,
+//! as well as fixtures covering the edge cases of digit sequences.
+//!
+//! **REQUIRES FEATURE**: `testing`.
+//!
+//! ```
+//! use digit_sequence::*;
+//! use digit_sequence::testing::*;
+//!
+//! test_roundtrip_conversion(90210u32);
+//!
+//! for sequence in edge_case_sequences() {
+//!     test_roundtrip_parsing(&sequence);
+//! }
+//!
+//! assert_eq!(cyclic_sequence(12).to_string(), "012345678901");
+//! ```
+
+use crate::DigitSequence;
+use core::error::Error;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::str::FromStr;
+use pretty_assertions::assert_eq as eq;
+
+/// Asserts that the given value survives the conversion
+/// to [DigitSequence] and back.
+pub fn test_roundtrip_conversion<E, T>(source: T)
+where
+    E: Error,
+    T: Into<DigitSequence> + TryFrom<DigitSequence, Error = E> + PartialEq<T> + Debug + Copy,
+{
+    let sequence: DigitSequence = source.into();
+    let roundtrip: T = sequence.try_into().unwrap();
+
+    eq!(roundtrip, source);
+}
+
+/// Asserts that the given value survives the conversion
+/// to [DigitSequence] and back - from a reference to the sequence.
+pub fn test_roundtrip_conversion_via_ref<E, T>(source: T)
+where
+    E: Error,
+    T: Into<DigitSequence>
+        + for<'a> TryFrom<&'a DigitSequence, Error = E>
+        + PartialEq<T>
+        + Debug
+        + Copy,
+{
+    let sequence: DigitSequence = source.into();
+    let reference = &sequence;
+    let roundtrip: T = reference.try_into().unwrap();
+
+    eq!(roundtrip, source);
+}
+
+/// Asserts that the given value survives formatting via [Display]
+/// and parsing via [FromStr].
+pub fn test_roundtrip_parsing<T>(source: &T)
+where
+    T: Display + FromStr<Err: Debug> + PartialEq<T> + Debug,
+{
+    let roundtrip: T = source.to_string().parse().unwrap();
+
+    eq!(&roundtrip, source);
+}
+
+/// Sequence of the given length, repeating the 0-9 digits in order.
+pub fn cyclic_sequence(len: usize) -> DigitSequence {
+    DigitSequence((0..len).map(|index| (index % 10) as u8).collect())
+}
+
+/// Digits of [short_sequences] - deliberately not in order, so that
+/// misplaced digits cannot go unnoticed.
+const SHORT_SEQUENCE_PATTERN: [u8; 10] = [9, 0, 8, 1, 7, 2, 6, 3, 5, 4];
+
+/// Sequences having every length from 0 to `max_len` - both inclusive - handy
+/// for testing storage types across the boundaries of chunks, bytes or blocks.
+pub fn short_sequences(max_len: usize) -> impl Iterator<Item = DigitSequence> {
+    (0..=max_len).map(|len| {
+        DigitSequence(
+            (0..len)
+                .map(|index| SHORT_SEQUENCE_PATTERN[index % SHORT_SEQUENCE_PATTERN.len()])
+                .collect(),
+        )
+    })
+}
+
+/// Sequences at the boundaries of the crate's behavior: the empty sequence,
+/// the single digits, leading zeros, the largest [u128] and its successor,
+/// the inline capacity of the `smallvec` feature and a long sequence.
+pub fn edge_case_sequences() -> Vec<DigitSequence> {
+    let u128_successor: DigitSequence = "340282366920938463463374607431768211456"
+        .parse()
+        .expect("The successor of u128::MAX is a valid sequence");
+
+    vec![
+        DigitSequence::new(),
+        0u8.into(),
+        9u8.into(),
+        crate::digits![0, 0, 7],
+        u128::MAX.into(),
+        u128_successor,
+        cyclic_sequence(24),
+        cyclic_sequence(25),
+        cyclic_sequence(1000),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speculate2::*;
+
+    speculate! {
+        describe "Edge-case sequences" {
+            it "should be distinct" {
+                let sequences = edge_case_sequences();
+
+                for (index, sequence) in sequences.iter().enumerate() {
+                    eq!(sequences[..index].contains(sequence), false);
+                }
+            }
+        }
+
+        describe "Short sequences" {
+            it "should have every length, as prefixes of the same pattern" {
+                let sequences: Vec<DigitSequence> = short_sequences(12).collect();
+
+                eq!(sequences.len(), 13);
+                eq!(sequences[12].to_string(), "908172635490");
+
+                for (length, sequence) in sequences.iter().enumerate() {
+                    eq!(sequence.iter().len(), length);
+                    eq!(sequences[12].to_string().starts_with(&sequence.to_string()), true);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{CrateError, CrateResult, DigitSequence};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The resolution of a decimal timestamp - counted since the Unix epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TimestampUnit {
+    /// Seconds - as in classic Unix timestamps.
+    #[default]
+    Seconds,
+
+    /// Milliseconds - as in most Snowflake-like ID schemes.
+    Millis,
+}
+
+impl DigitSequence {
+    /// Converts a point in time to the decimal digits of the seconds
+    /// or milliseconds elapsed since the Unix epoch - truncating any finer
+    /// resolution; times before the epoch result in a [CrateError::Underflow].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    ///
+    /// let sequence = DigitSequence::from_system_time(time, TimestampUnit::Seconds)?;
+    /// assert_eq!(sequence.to_string(), "1700000000");
+    ///
+    /// let sequence = DigitSequence::from_system_time(time, TimestampUnit::Millis)?;
+    /// assert_eq!(sequence.to_string(), "1700000000123");
+    ///
+    /// let result = DigitSequence::from_system_time(UNIX_EPOCH - Duration::from_secs(1), TimestampUnit::Seconds);
+    /// assert_eq!(result, Err(CrateError::Underflow));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_system_time(time: SystemTime, unit: TimestampUnit) -> CrateResult<DigitSequence> {
+        let elapsed = time
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| CrateError::Underflow)?;
+
+        let value = match unit {
+            TimestampUnit::Seconds => elapsed.as_secs() as u128,
+            TimestampUnit::Millis => elapsed.as_millis(),
+        };
+
+        Ok(value.into())
+    }
+
+    /// Interprets the sequence as the seconds or milliseconds elapsed
+    /// since the Unix epoch - leading zeros included, so fixed-width fields
+    /// extracted from an ID can be converted directly; a value not
+    /// representable by [SystemTime] results in a [CrateError::Overflow].
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let id: DigitSequence = "1700000000123000042".parse()?;
+    /// let timestamp = id.iter().copied().take_sequence(13)?;
+    ///
+    /// assert_eq!(
+    ///     timestamp.to_system_time(TimestampUnit::Millis)?,
+    ///     UNIX_EPOCH + Duration::from_millis(1_700_000_000_123)
+    /// );
+    ///
+    /// let padded: DigitSequence = "0000000042".parse()?;
+    /// assert_eq!(padded.to_system_time(TimestampUnit::Seconds)?, UNIX_EPOCH + Duration::from_secs(42));
+    ///
+    /// let huge: DigitSequence = "99999999999999999999999".parse()?;
+    /// assert_eq!(huge.to_system_time(TimestampUnit::Seconds), Err(CrateError::Overflow));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_system_time(&self, unit: TimestampUnit) -> CrateResult<SystemTime> {
+        let value: u64 = self.try_into()?;
+
+        let elapsed = match unit {
+            TimestampUnit::Seconds => Duration::from_secs(value),
+            TimestampUnit::Millis => Duration::from_millis(value),
+        };
+
+        UNIX_EPOCH.checked_add(elapsed).ok_or(CrateError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Converting a system time" {
+            it "should round-trip the current time in both units" {
+                let now = SystemTime::now();
+
+                for unit in [TimestampUnit::Seconds, TimestampUnit::Millis] {
+                    let sequence = DigitSequence::from_system_time(now, unit).unwrap();
+                    let elapsed = now.duration_since(sequence.to_system_time(unit).unwrap()).unwrap();
+
+                    assert!(elapsed < Duration::from_secs(1));
+                }
+            }
+
+            it "should convert the epoch to zero" {
+                eq!(DigitSequence::from_system_time(UNIX_EPOCH, TimestampUnit::Millis).unwrap(), [0]);
+            }
+
+            it "should convert the empty sequence to the epoch" {
+                eq!(DigitSequence::new().to_system_time(TimestampUnit::Seconds), Ok(UNIX_EPOCH));
+            }
+        }
+    }
+}
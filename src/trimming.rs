@@ -0,0 +1,198 @@
+use crate::arithmetic::significant_digits;
+use crate::{DigitSequence, Digits};
+
+impl DigitSequence {
+    /// Number of zeros preceding the first non-zero digit - which is the
+    /// whole length, for a sequence made of zeros only.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = "007100".parse()?;
+    /// assert_eq!(sequence.leading_zeros(), 2);
+    ///
+    /// let zeros: DigitSequence = "000".parse()?;
+    /// assert_eq!(zeros.leading_zeros(), 3);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn leading_zeros(&self) -> usize {
+        self.0.len() - significant_digits(&self.0).len()
+    }
+
+    /// Number of zeros following the last non-zero digit - which is the
+    /// whole length, for a sequence made of zeros only.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = "007100".parse()?;
+    /// assert_eq!(sequence.trailing_zeros(), 2);
+    ///
+    /// let zeros: DigitSequence = "000".parse()?;
+    /// assert_eq!(zeros.trailing_zeros(), 3);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn trailing_zeros(&self) -> usize {
+        self.0.len() - self.len_without_trailing_zeros()
+    }
+
+    /// Tells whether the sequence is in the canonical form returned by
+    /// [normalized](DigitSequence::normalized): no leading zeros, with 0
+    /// represented by the single digit 0 - so the empty sequence is not normalized.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// assert!("7100".parse::<DigitSequence>()?.is_normalized());
+    /// assert!("0".parse::<DigitSequence>()?.is_normalized());
+    ///
+    /// assert!(!"0710".parse::<DigitSequence>()?.is_normalized());
+    /// assert!(!"00".parse::<DigitSequence>()?.is_normalized());
+    /// assert!(!DigitSequence::new().is_normalized());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_normalized(&self) -> bool {
+        match self.0.first() {
+            Some(0) => self.0.len() == 1,
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    /// New sequence without the leading zeros - so a sequence made
+    /// of zeros only becomes empty; to keep a single 0 instead,
+    /// use [normalized](DigitSequence::normalized).
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = "007100".parse()?;
+    /// assert_eq!(sequence.trim_leading_zeros(), [7, 1, 0, 0]);
+    ///
+    /// let zeros: DigitSequence = "000".parse()?;
+    /// assert_eq!(zeros.trim_leading_zeros(), []);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn trim_leading_zeros(&self) -> DigitSequence {
+        DigitSequence(Digits::from(significant_digits(&self.0)))
+    }
+
+    /// New sequence without the trailing zeros - so a sequence made
+    /// of zeros only becomes empty.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = "007100".parse()?;
+    /// assert_eq!(sequence.trim_trailing_zeros(), [0, 0, 7, 1]);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn trim_trailing_zeros(&self) -> DigitSequence {
+        DigitSequence(Digits::from(&self.0[..self.len_without_trailing_zeros()]))
+    }
+
+    /// Removes the leading zeros, just like [trim_leading_zeros](DigitSequence::trim_leading_zeros),
+    /// but without allocating a new sequence.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let mut sequence: DigitSequence = "007100".parse()?;
+    ///
+    /// sequence.trim_leading_zeros_in_place();
+    /// assert_eq!(sequence, [7, 1, 0, 0]);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn trim_leading_zeros_in_place(&mut self) {
+        let leading_zeros = self.leading_zeros();
+
+        self.0.drain(..leading_zeros);
+    }
+
+    /// Removes the trailing zeros, just like [trim_trailing_zeros](DigitSequence::trim_trailing_zeros),
+    /// but without allocating a new sequence.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let mut sequence: DigitSequence = "007100".parse()?;
+    ///
+    /// sequence.trim_trailing_zeros_in_place();
+    /// assert_eq!(sequence, [0, 0, 7, 1]);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn trim_trailing_zeros_in_place(&mut self) {
+        self.0.truncate(self.len_without_trailing_zeros());
+    }
+
+    /// Length of the sequence once its trailing zeros are removed.
+    fn len_without_trailing_zeros(&self) -> usize {
+        self.0
+            .iter()
+            .rposition(|&digit| digit != 0)
+            .map_or(0, |last_non_zero| last_non_zero + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Counting zeros" {
+            it "should agree with normalization" {
+                for source in ["", "0", "00", "7", "0070", "1001", "900"] {
+                    let sequence: DigitSequence = source.parse().unwrap();
+                    let normalized: DigitSequence = sequence.clone().normalized().into();
+
+                    eq!(sequence.is_normalized(), normalized == sequence);
+                    eq!(sequence.leading_zeros() + sequence.trim_leading_zeros().iter().len(), source.len());
+                    eq!(sequence.trailing_zeros() + sequence.trim_trailing_zeros().iter().len(), source.len());
+                }
+            }
+        }
+
+        describe "Trimming zeros" {
+            it "should agree with the string trimming" {
+                for source in ["", "0", "000", "7", "0070", "1001", "900", "00900"] {
+                    let sequence: DigitSequence = source.parse().unwrap();
+
+                    let mut leading = sequence.clone();
+                    leading.trim_leading_zeros_in_place();
+
+                    let mut trailing = sequence.clone();
+                    trailing.trim_trailing_zeros_in_place();
+
+                    eq!(sequence.trim_leading_zeros().to_string(), source.trim_start_matches('0'));
+                    eq!(leading.to_string(), source.trim_start_matches('0'));
+
+                    eq!(sequence.trim_trailing_zeros().to_string(), source.trim_end_matches('0'));
+                    eq!(trailing.to_string(), source.trim_end_matches('0'));
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,12 @@
-use crate::{CrateError, CrateResult, DigitSequence};
+use crate::{CrateError, CrateResult, DigitSequence, DigitSlice, Digits};
+use alloc::{boxed::Box, vec::Vec};
 
 /// [DigitSequence] can be created from a [Vec] of [u8], as long as
 /// the vector contains only 0-9 digits or is empty; otherwise,
-/// the conversion results in a [CrateError::NonDigitNumber]:
+/// the conversion results in a [CrateError::NonDigitNumber].
+///
+/// The digits are validated in place, and the sequence takes ownership
+/// of the vector's allocation - without copying it.
 ///
 /// ```
 /// use digit_sequence::*;
@@ -25,10 +29,86 @@ impl TryFrom<Vec<u8>> for DigitSequence {
     type Error = CrateError;
 
     fn try_from(value: Vec<u8>) -> CrateResult<Self> {
-        (&value as &[u8]).try_into()
+        DigitSlice::try_from(&value[..])?;
+
+        Ok(DigitSequence(into_digits(value)))
+    }
+}
+
+/// [DigitSequence] can be created from a boxed slice of [u8] - just like [Vec],
+/// taking ownership of its allocation:
+///
+/// ```
+/// use digit_sequence::*;
+///
+/// # fn main() -> GenericResult<()> {
+///
+/// let boxed: Box<[u8]> = Box::new([9, 2]);
+/// let sequence: DigitSequence = boxed.try_into()?;
+/// assert_eq!(sequence, [9, 2]);
+///
+/// let boxed: Box<[u8]> = Box::new([10]);
+/// let result: CrateResult<DigitSequence> = boxed.try_into();
+/// assert_eq!(result, Err(CrateError::NonDigitNumber(10)));
+///
+/// # Ok(())
+/// # }
+/// ```
+impl TryFrom<Box<[u8]>> for DigitSequence {
+    type Error = CrateError;
+
+    fn try_from(value: Box<[u8]>) -> CrateResult<Self> {
+        value.into_vec().try_into()
     }
 }
 
+impl DigitSequence {
+    /// Creates a sequence from a vector whose items are already known to be
+    /// 0-9 digits - for example, because they were decoded from packed BCD
+    /// or produced by a generator - taking ownership of its allocation
+    /// and skipping the O(n) validation performed by [TryFrom].
+    ///
+    /// In debug builds, the digits are validated anyway, panicking on failure.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let digits: Vec<u8> = (0..20).map(|index| index % 10).collect();
+    ///
+    /// // SAFETY: every item is the remainder of a division by 10
+    /// let sequence = unsafe { DigitSequence::from_vec_unchecked(digits) };
+    ///
+    /// assert_eq!(sequence.to_string(), "01234567890123456789");
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Every item of the vector must be a 0-9 digit: the methods of
+    /// [DigitSequence] rely on this invariant, and their behavior is
+    /// unspecified - they may panic or return wrong results - if it is violated.
+    pub unsafe fn from_vec_unchecked(digits: Vec<u8>) -> DigitSequence {
+        debug_assert!(
+            digits.iter().all(|&digit| digit < 10),
+            "The vector must only contain 0-9 digits"
+        );
+
+        DigitSequence(into_digits(digits))
+    }
+}
+
+/// Reuses the allocation of an already validated buffer.
+#[cfg(not(feature = "smallvec"))]
+pub(crate) fn into_digits(buffer: Vec<u8>) -> Digits {
+    buffer
+}
+
+/// Reuses the allocation of an already validated buffer - unless
+/// it is short enough to be stored inline.
+#[cfg(feature = "smallvec")]
+pub(crate) fn into_digits(buffer: Vec<u8>) -> Digits {
+    Digits::from_vec(buffer)
+}
+
 /// [DigitSequence] can be created from a
 /// reference to a [Vec] of [u8], as long as the vector
 /// only contains 0-9 digits or is empty; otherwise,
@@ -78,7 +158,7 @@ impl TryFrom<&Vec<u8>> for DigitSequence {
 /// ```
 impl PartialEq<Vec<u8>> for DigitSequence {
     fn eq(&self, other: &Vec<u8>) -> bool {
-        self.0 == *other
+        self.0[..] == other[..]
     }
 }
 
@@ -102,6 +182,50 @@ impl PartialEq<Vec<u8>> for DigitSequence {
 /// ```
 impl PartialEq<&Vec<u8>> for DigitSequence {
     fn eq(&self, other: &&Vec<u8>) -> bool {
-        self.0 == **other
+        self.0[..] == other[..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Converting an owned buffer" {
+            it "should reuse the allocation of a long vector" {
+                let buffer = vec![7u8; 1000];
+                let address = buffer.as_ptr();
+
+                let sequence = DigitSequence::try_from(buffer).unwrap();
+
+                eq!(sequence.0.as_ptr(), address);
+            }
+
+            it "should reuse the allocation of a long boxed slice" {
+                let buffer: Box<[u8]> = vec![7u8; 1000].into_boxed_slice();
+                let address = buffer.as_ptr();
+
+                let sequence = DigitSequence::try_from(buffer).unwrap();
+
+                eq!(sequence.0.as_ptr(), address);
+            }
+
+            it "should report the first non-digit" {
+                eq!(DigitSequence::try_from(vec![1, 20, 30]), Err(CrateError::NonDigitNumber(20)));
+            }
+        }
+
+        describe "Converting a trusted buffer" {
+            it "should reuse the allocation of a long vector" {
+                let buffer = vec![7u8; 1000];
+                let address = buffer.as_ptr();
+
+                let sequence = unsafe { DigitSequence::from_vec_unchecked(buffer) };
+
+                eq!(sequence.0.as_ptr(), address);
+            }
+        }
     }
 }
@@ -0,0 +1,400 @@
+use crate::arithmetic::significant_digits;
+use crate::{CrateError, CrateResult, DigitSequence};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Short-scale names of the powers of 1000, starting from 1000^1.
+const SCALES: [&str; 21] = [
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+    "duodecillion",
+    "tredecillion",
+    "quattuordecillion",
+    "quindecillion",
+    "sexdecillion",
+    "septendecillion",
+    "octodecillion",
+    "novemdecillion",
+    "vigintillion",
+];
+
+fn push_group_words(words: &mut Vec<String>, group: u16) {
+    let hundreds = group / 100;
+    let remainder = group % 100;
+
+    if hundreds > 0 {
+        words.push(UNITS[hundreds as usize].to_string());
+        words.push("hundred".to_string());
+    }
+
+    match remainder {
+        0 => (),
+        1..=19 => words.push(UNITS[remainder as usize].to_string()),
+        _ => {
+            let tens = TENS[(remainder / 10) as usize];
+
+            words.push(match remainder % 10 {
+                0 => tens.to_string(),
+                units => format!("{}-{}", tens, UNITS[units as usize]),
+            });
+        }
+    }
+}
+
+impl DigitSequence {
+    /// Spells out the represented value in English words, using the short scale;
+    /// sequences much longer than [u128] are supported, up to
+    /// the *vigintillion* (10<sup>63</sup>) range.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = 1234u16.into();
+    /// assert_eq!(sequence.to_words_en()?, "one thousand two hundred thirty-four");
+    ///
+    /// let sequence: DigitSequence = [0, 0, 7].try_into()?;
+    /// assert_eq!(sequence.to_words_en()?, "seven");
+    ///
+    /// let sequence: DigitSequence = 0u8.into();
+    /// assert_eq!(sequence.to_words_en()?, "zero");
+    ///
+    /// let sequence: DigitSequence = format!("2{}", "0".repeat(60)).parse()?;
+    /// assert_eq!(sequence.to_words_en()?, "two novemdecillion");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Values from 10<sup>66</sup> onward result in [CrateError::Overflow]:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence: DigitSequence = format!("1{}", "0".repeat(66)).parse()?;
+    /// assert_eq!(sequence.to_words_en(), Err(CrateError::Overflow));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_words_en(&self) -> CrateResult<String> {
+        let digits = significant_digits(&self.0);
+
+        if digits.is_empty() {
+            return Ok(UNITS[0].to_string());
+        }
+
+        let group_count = digits.len().div_ceil(3);
+
+        if group_count > SCALES.len() + 1 {
+            return Err(CrateError::Overflow);
+        }
+
+        let first_group_len = digits.len() - (group_count - 1) * 3;
+
+        let groups = core::iter::once(&digits[..first_group_len])
+            .chain(digits[first_group_len..].chunks(3))
+            .map(|group| {
+                group
+                    .iter()
+                    .fold(0u16, |value, &digit| value * 10 + digit as u16)
+            });
+
+        let mut words = Vec::new();
+
+        for (index, group) in groups.enumerate() {
+            if group == 0 {
+                continue;
+            }
+
+            push_group_words(&mut words, group);
+
+            let scale_index = group_count - index - 1;
+
+            if scale_index > 0 {
+                words.push(SCALES[scale_index - 1].to_string());
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+}
+
+/// Splits the input into lowercase words - separated by whitespace or hyphens -
+/// each paired with its byte offset.
+fn tokenize(input: &str) -> Vec<(usize, String)> {
+    let mut words = Vec::new();
+    let mut current_start = None;
+
+    for (offset, current_char) in input.char_indices().chain([(input.len(), ' ')]) {
+        let is_separator = current_char.is_whitespace() || current_char == '-';
+
+        match (current_start, is_separator) {
+            (None, false) => current_start = Some(offset),
+            (Some(start), true) => {
+                words.push((start, input[start..offset].to_lowercase()));
+                current_start = None;
+            }
+            _ => (),
+        }
+    }
+
+    words
+}
+
+#[derive(Default)]
+struct GroupParser {
+    hundreds: Option<u16>,
+    tens: Option<u16>,
+    units: Option<u16>,
+}
+
+impl GroupParser {
+    fn value(&self) -> u16 {
+        self.hundreds.unwrap_or(0) * 100 + self.tens.unwrap_or(0) + self.units.unwrap_or(0)
+    }
+
+    fn accept(&mut self, word: &str) -> bool {
+        if let Some(unit) = UNITS[1..].iter().position(|&name| name == word) {
+            let unit = unit as u16 + 1;
+
+            if self.units.is_some() || (unit >= 10 && self.tens.is_some()) {
+                return false;
+            }
+
+            self.units = Some(unit);
+            return true;
+        }
+
+        if let Some(tens) = TENS
+            .iter()
+            .position(|&name| !name.is_empty() && name == word)
+        {
+            if self.tens.is_some() || self.units.is_some() {
+                return false;
+            }
+
+            self.tens = Some(tens as u16 * 10);
+            return true;
+        }
+
+        if word == "hundred" {
+            return match (self.hundreds, self.tens, self.units) {
+                (None, None, Some(unit)) if unit < 10 => {
+                    self.hundreds = Some(unit);
+                    self.units = None;
+                    true
+                }
+                _ => false,
+            };
+        }
+
+        false
+    }
+}
+
+impl DigitSequence {
+    /// Parses English number words - the inverse of
+    /// [to_words_en](DigitSequence::to_words_en); the result has no leading zeros.
+    ///
+    /// Words are case-insensitive and separated by whitespace or hyphens;
+    /// `and` is accepted wherever it is customary, and thus ignored.
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let sequence = DigitSequence::from_words_en("four hundred twenty")?;
+    /// assert_eq!(sequence, [4, 2, 0]);
+    ///
+    /// let sequence = DigitSequence::from_words_en("One Thousand and Twenty-One")?;
+    /// assert_eq!(sequence, [1, 0, 2, 1]);
+    ///
+    /// let sequence = DigitSequence::from_words_en("zero")?;
+    /// assert_eq!(sequence, [0]);
+    ///
+    /// let sequence = DigitSequence::from_words_en("seven vigintillion")?;
+    /// assert_eq!(sequence.to_string(), format!("7{}", "0".repeat(63)));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Any unknown or misplaced word results in a [CrateError::UnexpectedWord],
+    /// whose offset can be used to locate the issue in the input:
+    ///
+    /// ```
+    /// use digit_sequence::*;
+    ///
+    /// let input = "two hundred twelve seven";
+    /// let result = DigitSequence::from_words_en(input);
+    ///
+    /// assert_eq!(result, Err(CrateError::UnexpectedWord { offset: 19 }));
+    /// assert_eq!(&input[19..], "seven");
+    ///
+    /// let result = DigitSequence::from_words_en("one potato");
+    /// assert_eq!(result, Err(CrateError::UnexpectedWord { offset: 4 }));
+    ///
+    /// let result = DigitSequence::from_words_en("one thousand one million");
+    /// assert_eq!(result, Err(CrateError::UnexpectedWord { offset: 17 }));
+    ///
+    /// let result = DigitSequence::from_words_en("");
+    /// assert_eq!(result, Err(CrateError::UnexpectedWord { offset: 0 }));
+    /// ```
+    pub fn from_words_en(input: &str) -> CrateResult<DigitSequence> {
+        let words = tokenize(input);
+
+        match words.as_slice() {
+            [] => return Err(CrateError::UnexpectedWord { offset: 0 }),
+            [(_, word)] if word == UNITS[0] => return Ok(DigitSequence::from(0u8)),
+            _ => (),
+        }
+
+        let mut groups = vec![0u16; SCALES.len() + 1];
+        let mut last_scale: Option<usize> = None;
+        let mut current_group = GroupParser::default();
+        let mut has_content = false;
+
+        for (offset, word) in &words {
+            let unexpected_word = CrateError::UnexpectedWord { offset: *offset };
+
+            if word == "and" && has_content {
+                continue;
+            }
+
+            if let Some(scale) = SCALES.iter().position(|name| name == word) {
+                let scale = scale + 1;
+
+                if current_group.value() == 0 || last_scale.is_some_and(|last| scale >= last) {
+                    return Err(unexpected_word);
+                }
+
+                groups[scale] = current_group.value();
+                last_scale = Some(scale);
+                current_group = GroupParser::default();
+                continue;
+            }
+
+            if !current_group.accept(word) {
+                return Err(unexpected_word);
+            }
+
+            has_content = true;
+        }
+
+        groups[0] = current_group.value();
+
+        let digits: String = groups
+            .iter()
+            .rev()
+            .map(|group| format!("{:03}", group))
+            .collect();
+
+        let digits = digits.bytes().map(|digit| digit - b'0').collect();
+
+        Ok(DigitSequence(crate::arithmetic::normalize(digits)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Spelling out in English" {
+            fn test_case(value: u128, expected: &str) {
+                eq!(DigitSequence::from_words_en(expected).unwrap(), DigitSequence::from(value));
+
+                eq!(DigitSequence::from(value).to_words_en().unwrap(), expected);
+            }
+
+            it "should spell the teens" {
+                test_case(13, "thirteen");
+            }
+
+            it "should spell round tens" {
+                test_case(40, "forty");
+            }
+
+            it "should spell hundreds with units" {
+                test_case(905, "nine hundred five");
+            }
+
+            it "should skip empty groups" {
+                test_case(1_000_000_021, "one billion twenty-one");
+            }
+
+            it "should spell u128::MAX" {
+                test_case(
+                    u128::MAX,
+                    "three hundred forty undecillion two hundred eighty-two decillion \
+                    three hundred sixty-six nonillion nine hundred twenty octillion \
+                    nine hundred thirty-eight septillion four hundred sixty-three sextillion \
+                    four hundred sixty-three quintillion three hundred seventy-four quadrillion \
+                    six hundred seven trillion four hundred thirty-one billion \
+                    seven hundred sixty-eight million two hundred eleven thousand \
+                    four hundred fifty-five"
+                );
+            }
+
+            it "should support roundtrip with parsing" {
+                for value in (0u32..=2000).chain([12_345, 100_001, 999_999_999, 1_000_000_000]) {
+                    let sequence = DigitSequence::from(value);
+                    let words = sequence.to_words_en().unwrap();
+
+                    eq!(DigitSequence::from_words_en(&words).unwrap(), sequence, "{}", words);
+                }
+            }
+
+            it "should support the largest value" {
+                let sequence: DigitSequence = "9".repeat(66).parse().unwrap();
+
+                assert!(sequence.to_words_en().unwrap().starts_with("nine hundred ninety-nine vigintillion"));
+            }
+        }
+    }
+}